@@ -2,7 +2,7 @@
 
 if [ -z "$1" ]; then
   echo "Provide a day number."
-  echo "usage: $0 DAY"
+  echo "usage: $0 DAY [YEAR]"
   exit 1
 fi
 
@@ -11,8 +11,12 @@ if [ -z "$AOC_TOKEN" ]; then
   exit 1
 fi
 
-URL="https://adventofcode.com/2022/day/$1/input"
-curl -A "https://github.com/schmidtwmark/advent-of-code2022/blob/main/fetch.sh by mark.schmidt@hey.com" $URL --cookie $AOC_TOKEN > inputs/$1.txt
-touch samples/$1.txt
-cp template.rs src/bin/$1.rs
-sed -i '' -e "s/aaaaa/$1/g"  src/bin/$1.rs
+# Default to the year in aoc.toml
+YEAR=${2:-$(sed -n 's/^year *= *\([0-9]*\).*/\1/p' aoc.toml)}
+
+URL="https://adventofcode.com/$YEAR/day/$1/input"
+mkdir -p inputs/$YEAR samples/$YEAR
+curl -A "https://github.com/schmidtwmark/advent-of-code2022/blob/main/fetch.sh by mark.schmidt@hey.com" $URL --cookie $AOC_TOKEN > inputs/$YEAR/$1.txt
+touch samples/$YEAR/$1.txt
+cp template.rs src/bin/$YEAR-$1.rs
+sed -i '' -e "s/aaaaa/$1/g" -e "s/yyyy/$YEAR/g" src/bin/$YEAR-$1.rs
@@ -1,19 +1,13 @@
 #!/bin/sh
 
-# Set the default log level to 'info'
-log_level=${2:-info}
-
-# Set the RUST_LOG environment variable
-export RUST_LOG=$log_level
-
 # Get the day number from the command-line arguments
 day_number=$1
 
-# Set the default part number to 1
-part_number=${3:-all}
-
-# Set the default input number to 0
-input_number=${4:-all}
+# Log level, part, input and year default to the values in aoc.toml
+log_level=$2
+part_number=$3
+input_number=$4
+year=$(sed -n 's/^year *= *\([0-9]*\).*/\1/p' aoc.toml)
 
 # Parse the command-line arguments
 while [ $# -gt 0 ]; do
@@ -26,9 +20,18 @@ while [ $# -gt 0 ]; do
       shift
       input_number=$1
       ;;
+    -y|--year)
+      shift
+      year=$1
+      ;;
   esac
   shift
 done
 
+set --
+[ -n "$log_level" ] && set -- "$@" --log-level "$log_level"
+[ -n "$part_number" ] && set -- "$@" -p "$part_number"
+[ -n "$input_number" ] && set -- "$@" -t "$input_number"
+
 # Run the program with the specified options
-cargo run --release --bin $day_number -- -p $part_number -t $input_number
\ No newline at end of file
+cargo run --release --bin $year-$day_number -- "$@"
@@ -0,0 +1,141 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::fmt::Display;
+
+/// Heap usage of a single run.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize)]
+pub struct MemoryStats {
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut size = self.peak_bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(
+            f,
+            "peak {:.1} {} in {} allocations",
+            size, UNITS[unit], self.allocations
+        )
+    }
+}
+
+// Counters are per thread so runs on parallel threads don't see each other's
+// allocations. Memory freed by a different thread than the one that allocated
+// it is credited to the freeing thread.
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record(delta: isize, allocation: bool) {
+    // Thread locals may already be gone while a thread is shutting down
+    let _ = CURRENT.try_with(|current| {
+        let now = current.get() + delta;
+        current.set(now);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+    if allocation {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    }
+}
+
+/// Wraps the system allocator, counting allocations and the peak number of
+/// live bytes on each thread. Installed as the global allocator by the
+/// `track-alloc` feature.
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize), false);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize, true);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "track-alloc")]
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
+pub fn enabled() -> bool {
+    cfg!(feature = "track-alloc")
+}
+
+/// Runs `f`, returning its heap usage on this thread if tracking is enabled.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<MemoryStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    CURRENT.with(|current| current.set(0));
+    PEAK.with(|peak| peak.set(0));
+    let allocations_before = ALLOCATIONS.with(Cell::get);
+
+    let result = f();
+
+    let stats = MemoryStats {
+        peak_bytes: PEAK.with(Cell::get).max(0) as usize,
+        allocations: ALLOCATIONS.with(Cell::get) - allocations_before,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let stats = MemoryStats {
+            peak_bytes: 3 * 1024 * 1024 / 2,
+            allocations: 12,
+        };
+        assert_eq!(stats.to_string(), "peak 1.5 MiB in 12 allocations");
+    }
+
+    #[cfg(feature = "track-alloc")]
+    #[test]
+    fn test_measure_counts_this_thread() {
+        let (_, stats) = measure(|| {
+            let big = vec![0u8; 1 << 20];
+            drop(big);
+            let small = vec![0u8; 1 << 10];
+            small.len()
+        });
+        let stats = stats.unwrap();
+        assert!(stats.peak_bytes >= 1 << 20);
+        assert!(stats.allocations >= 2);
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+
+use crate::{Connectivity, Grid, Point, SparseGrid};
+
+type Pos = (usize, usize);
+
+/// A grid an automaton can run on. Missing cells read as `None`: cells off
+/// a dense grid, or empty cells of a sparse one.
+pub trait Space: Sized {
+    type Value;
+    type Pos: Copy;
+
+    fn lookup(&self, pos: Self::Pos, offset: Point) -> Option<&Self::Value>;
+
+    /// The next generation with `rule` applied to every cell that can change,
+    /// and how many cells changed.
+    fn next(
+        &self,
+        connectivity: Connectivity,
+        rule: &Rule<Self>,
+        generation: usize,
+    ) -> (Self, usize);
+}
+
+pub type Rule<'r, S> = dyn Fn(&Cell<S>) -> Option<<S as Space>::Value> + 'r;
+
+/// The cell a rule decides the next value of, with the current generation
+/// around it. A rule returns `None` to leave the cell empty, which on dense
+/// grids means `T::default()`.
+pub struct Cell<'s, S: Space> {
+    space: &'s S,
+    pos: S::Pos,
+    connectivity: Connectivity,
+    generation: usize,
+}
+
+impl<'s, S: Space> Cell<'s, S> {
+    pub fn pos(&self) -> S::Pos {
+        self.pos
+    }
+
+    /// Number of steps run before this one.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn value(&self) -> Option<&'s S::Value> {
+        self.space.lookup(self.pos, Point::ORIGIN)
+    }
+
+    /// The cell at `offset` from this one, which need not be a neighbour.
+    pub fn get(&self, offset: impl Into<Point>) -> Option<&'s S::Value> {
+        self.space.lookup(self.pos, offset.into())
+    }
+
+    /// The cells in the automaton's neighbourhood that are present.
+    pub fn neighbors(&self) -> impl Iterator<Item = &'s S::Value> + '_ {
+        let (space, pos) = (self.space, self.pos);
+        self.connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| space.lookup(pos, offset.into()))
+    }
+
+    pub fn count(&self, matching: impl Fn(&S::Value) -> bool) -> usize {
+        self.neighbors().filter(|value| matching(value)).count()
+    }
+}
+
+impl<T: Default + Clone + PartialEq> Space for Grid<T> {
+    type Value = T;
+    type Pos = Pos;
+
+    fn lookup(&self, pos: Pos, offset: Point) -> Option<&T> {
+        self.offset_pos(pos, offset.into()).map(|pos| self.at(pos))
+    }
+
+    fn next(
+        &self,
+        connectivity: Connectivity,
+        rule: &Rule<Self>,
+        generation: usize,
+    ) -> (Self, usize) {
+        let next = Grid::from_fn(self.width, self.height, |pos| {
+            let cell = Cell {
+                space: self,
+                pos,
+                connectivity,
+                generation,
+            };
+            rule(&cell).unwrap_or_default()
+        });
+        let changes = next
+            .state
+            .iter()
+            .zip(&self.state)
+            .filter(|(a, b)| a != b)
+            .count();
+        (next, changes)
+    }
+}
+
+impl<T: PartialEq> Space for SparseGrid<T> {
+    type Value = T;
+    type Pos = Point;
+
+    fn lookup(&self, pos: Point, offset: Point) -> Option<&T> {
+        self.get(pos + offset)
+    }
+
+    /// Only occupied cells and their neighbours are considered, so rules must
+    /// leave cells with no occupied neighbours empty.
+    fn next(
+        &self,
+        connectivity: Connectivity,
+        rule: &Rule<Self>,
+        generation: usize,
+    ) -> (Self, usize) {
+        let candidates: HashSet<Point> = self
+            .positions()
+            .flat_map(|pos| {
+                connectivity
+                    .offsets()
+                    .iter()
+                    .map(move |&offset| pos + Point::from(offset))
+                    .chain([pos])
+            })
+            .collect();
+
+        let mut changes = 0;
+        let mut next = SparseGrid::new();
+        for pos in candidates {
+            let cell = Cell {
+                space: self,
+                pos,
+                connectivity,
+                generation,
+            };
+            let value = rule(&cell);
+            if value.as_ref() != self.get(pos) {
+                changes += 1;
+            }
+            if let Some(value) = value {
+                next.insert(pos, value);
+            }
+        }
+        (next, changes)
+    }
+}
+
+/// Runs a rule synchronously over every cell of a dense `Grid` or a
+/// `SparseGrid`, keeping count of how many cells each step changed.
+#[derive(Clone, Debug)]
+pub struct Automaton<S> {
+    pub space: S,
+    pub connectivity: Connectivity,
+    /// Cells changed by each step so far.
+    pub changes: Vec<usize>,
+}
+
+impl<S: Space> Automaton<S> {
+    pub fn new(space: S, connectivity: Connectivity) -> Self {
+        Automaton {
+            space,
+            connectivity,
+            changes: vec![],
+        }
+    }
+
+    /// Number of steps run.
+    pub fn generation(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Applies `rule` to every cell at once, returning how many changed.
+    pub fn step(&mut self, rule: &Rule<S>) -> usize {
+        let (next, changes) = self.space.next(self.connectivity, rule, self.generation());
+        self.space = next;
+        self.changes.push(changes);
+        changes
+    }
+
+    pub fn run(&mut self, steps: usize, rule: &Rule<S>) {
+        for _ in 0..steps {
+            self.step(rule);
+        }
+    }
+
+    /// Steps until nothing changes, returning the number of that last step.
+    /// `prepare` runs before every step and what it returns is passed to
+    /// `rule` for every cell, for state worked out once per generation.
+    pub fn run_until_stable<P>(
+        &mut self,
+        mut prepare: impl FnMut(&Self) -> P,
+        rule: impl Fn(&P, &Cell<S>) -> Option<S::Value>,
+    ) -> usize {
+        loop {
+            let prepared = prepare(self);
+            if self.step(&|cell| rule(&prepared, cell)) == 0 {
+                return self.generation();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life<S: Space<Value = bool>>(cell: &Cell<S>) -> Option<bool> {
+        let alive = cell.value() == Some(&true);
+        let neighbors = cell.count(|alive| *alive);
+        (neighbors == 3 || alive && neighbors == 2).then_some(true)
+    }
+
+    #[test]
+    fn test_dense_life() {
+        let grid = Grid::from_lines(&[".....", "..#..", "..#..", "..#..", "....."], &|c| {
+            c == '#'
+        });
+        let mut automaton = Automaton::new(grid.clone(), Connectivity::Eight);
+        assert_eq!(automaton.step(&life), 4);
+        assert_eq!(automaton.space, grid.transpose());
+        automaton.run(3, &life);
+        assert_eq!(automaton.space, grid);
+        assert_eq!(automaton.changes, [4, 4, 4, 4]);
+
+        let block = Grid::from_lines(&["....", ".##.", ".##.", "...."], &|c| c == '#');
+        let mut automaton = Automaton::new(block, Connectivity::Eight);
+        assert_eq!(automaton.run_until_stable(|_| (), |_, cell| life(cell)), 1);
+
+        // A blinker only settles once the rule stops letting it change
+        let blinker = Grid::from_lines(&["...", "###", "..."], &|c| c == '#');
+        let mut automaton = Automaton::new(blinker, Connectivity::Eight);
+        let frozen = |&generation: &usize, cell: &Cell<Grid<bool>>| match generation {
+            0..=2 => life(cell),
+            _ => cell.value().copied(),
+        };
+        let last = automaton.run_until_stable(|automaton| automaton.generation(), frozen);
+        assert_eq!(last, 4);
+        assert_eq!(automaton.changes, [4, 4, 4, 0]);
+    }
+
+    #[test]
+    fn test_sparse_life() {
+        // A glider moves one cell diagonally every four steps
+        let glider: SparseGrid<bool> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .iter()
+            .map(|&(x, y)| (Point::new(x, y), true))
+            .collect();
+        let mut automaton = Automaton::new(glider.clone(), Connectivity::Eight);
+        automaton.run(4, &life);
+        let moved: SparseGrid<bool> = glider
+            .iter()
+            .map(|(pos, _)| (pos + Point::new(1, 1), true))
+            .collect();
+        assert_eq!(automaton.space, moved);
+        assert_eq!(automaton.generation(), 4);
+    }
+}
@@ -2,20 +2,23 @@ use aoc::Solver;
 use itertools::Itertools;
 
 fn main() {
-    let sample = include_str!("../../samples/1.txt");
-    let input = include_str!("../../inputs/1.txt");
+    let file = "1.txt";
 
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 24000),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 24000),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 45000),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 45000),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 1),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
 
 struct Solution {}
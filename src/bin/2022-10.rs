@@ -93,19 +93,22 @@ impl Solver<'_, isize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/10.txt");
-    let sample_2 = include_str!("../../samples/10_2.txt");
-    let input = include_str!("../../inputs/10.txt");
+    let file = "10.txt";
+    let sample_2 = "10_2.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 13140),
-        aoc::Input::new_sample(sample_2, 0),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 13140),
+        aoc::Input::sample_file(sample_2, 0),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, Default::default()), // TODO: Fill in expected sample result
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, Default::default()), // TODO: Fill in expected sample result
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 10),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -184,17 +184,20 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/11.txt");
-    let input = include_str!("../../inputs/11.txt");
+    let file = "11.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 10605),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 10605),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 2713310158), // TODO: Fill in expected sample result
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 2713310158), // TODO: Fill in expected sample result
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 11),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use aoc::{Repl, Solver, Style};
+use itertools::Itertools;
+use log::{debug, info};
+
+fn in_range(a: &char, b: &char) -> bool {
+    *b as u8 <= *a as u8 + 1
+}
+
+/// The heightmap with `S` and `E` replaced by their heights, `a` and `z`.
+fn parse(lines: &[&str]) -> (aoc::Grid<char>, aoc::Markers) {
+    aoc::Grid::from_lines_with_markers(lines, &[('S', 'a'), ('E', 'z')], &|c| c)
+}
+
+fn height_color(c: &char) -> aoc::Rgb {
+    match c {
+        'S' => [0, 200, 0],
+        'E' => [220, 0, 0],
+        _ => aoc::gradient(
+            [20, 40, 120],
+            [240, 240, 240],
+            (*c as u8 - b'a') as f64 / 25.0,
+        ),
+    }
+}
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let (grid, markers) = parse(lines);
+
+        let start = markers.positions('S');
+        let end = markers.positions('E');
+        info!("Searching from {:?} to {:?}", start, end);
+
+        let result = grid.bfs(start, end, in_range);
+        let path = result.path().unwrap_or_default();
+        debug!("Path: {:?}", path);
+        debug!(
+            "{}",
+            grid.render(|pos, c| {
+                // Colour the start and end as markers rather than heights
+                let marker = ['S', 'E']
+                    .iter()
+                    .find(|&&marker| markers.positions(marker).contains(&pos));
+                let style = Style::PLAIN.bg(height_color(marker.unwrap_or(c)));
+                if path.contains(&pos) {
+                    style.fg([255, 220, 0]).bold()
+                } else {
+                    style.fg([0, 0, 0])
+                }
+            })
+        );
+        result.distance().unwrap()
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let (grid, markers) = parse(lines);
+
+        // `S` is at height `a` too
+        let starts = grid
+            .enumerate()
+            .filter(|(_, c)| **c == 'a')
+            .map(|(pos, _)| pos)
+            .collect_vec();
+        let end = markers.positions('E');
+        info!("Searching from {} starts to {:?}", starts.len(), end);
+
+        grid.bfs(&starts, end, in_range).distance().unwrap()
+    }
+
+    fn register_commands<'l>(&self, lines: &'l [&'l str], repl: &mut Repl<'l>) {
+        let grid = aoc::Grid::<char>::from_lines(lines, &|c| c);
+        repl.command(
+            "image",
+            "image <path> [scale]: save the heightmap as a PNG or PPM",
+            move |args| {
+                let path = args.first().ok_or("Missing path")?;
+                let scale = match args.get(1) {
+                    Some(scale) => scale.parse().map_err(|_| format!("Bad scale {scale}"))?,
+                    None => 4,
+                };
+                grid.save_image(Path::new(path), scale, height_color)
+                    .map_err(|e| e.to_string())?;
+                Ok(format!("Saved {path}"))
+            },
+        );
+    }
+}
+
+fn main() {
+    let file = "12.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 31),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 29),
+        aoc::Input::final_file(file),
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 12),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
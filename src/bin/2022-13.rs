@@ -170,17 +170,20 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/13.txt");
-    let input = include_str!("../../inputs/13.txt");
+    let file = "13.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 13),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 13),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 140),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 140),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 13),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
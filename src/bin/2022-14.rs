@@ -1,4 +1,4 @@
-use aoc::Solver;
+use aoc::{BitGrid, Recorder, Solver};
 use im::HashSet;
 use itertools::Itertools;
 use log::debug;
@@ -61,13 +61,36 @@ fn get_total_structure(lines: &[&str]) -> Rock {
     combined
 }
 
+/// The rock as a bitmap, big enough for the pile of sand on the floor of
+/// part two.
+fn rock_grid(rock: &Rock) -> BitGrid {
+    let floor = rock.lowest_height + 2;
+    BitGrid::from_points(500 + floor + 1, floor + 1, rock.structure.iter().copied())
+}
+
+/// Pushes the rock and the sand at rest as a recording frame.
+fn record(rock: &BitGrid, blocked: &BitGrid) {
+    if !Recorder::is_active() {
+        return;
+    }
+    let sand = blocked ^ rock;
+    Recorder::points(
+        rock.ones()
+            .map(|pos| (pos, '#'))
+            .chain(sand.ones().map(|pos| (pos, 'o'))),
+    );
+}
+
 struct Solution {}
 impl Solver<'_, usize> for Solution {
     fn solve_part_one(&self, lines: &[&str]) -> usize {
         let combined = get_total_structure(lines);
         let start_pos = (500, 0);
         let mut sand_pos = start_pos;
-        let mut sand_positions = std::collections::HashSet::new();
+        let rock = rock_grid(&combined);
+        let mut blocked = rock.clone();
+        let mut sand = 0;
+        Recorder::color('o', [230, 190, 100]);
 
         loop {
             let (sand_x, sand_y) = sand_pos;
@@ -78,15 +101,17 @@ impl Solver<'_, usize> for Solution {
                 // Sand will always fall
                 break;
             }
-            sand_pos = if combined.structure.contains(&below) || sand_positions.contains(&below) {
+            sand_pos = if blocked.get(below) {
                 // Sand is blocked, time to check if it can flow left or right
                 let left = (sand_x - 1, sand_y + 1);
                 let right = (sand_x + 1, sand_y + 1);
-                if combined.structure.contains(&left) || sand_positions.contains(&left) {
+                if blocked.get(left) {
                     // Sand is blocked on the left, check if it can flow right
-                    if combined.structure.contains(&right) || sand_positions.contains(&right) {
+                    if blocked.get(right) {
                         // Sand is blocked on the right, rest and reset
-                        sand_positions.insert(sand_pos);
+                        blocked.set(sand_pos, true);
+                        sand += 1;
+                        record(&rock, &blocked);
                         start_pos
                     } else {
                         // Sand can flow right
@@ -101,14 +126,17 @@ impl Solver<'_, usize> for Solution {
             }
         }
 
-        sand_positions.len()
+        sand
     }
 
     fn solve_part_two(&self, lines: &[&str]) -> usize {
         let combined = get_total_structure(lines);
         let start_pos = (500, 0);
         let mut sand_pos = start_pos;
-        let mut sand_positions = std::collections::HashSet::new();
+        let rock = rock_grid(&combined);
+        let mut blocked = rock.clone();
+        let mut sand = 0;
+        Recorder::color('o', [230, 190, 100]);
 
         let infinite_wall_height = combined.lowest_height + 2;
 
@@ -117,28 +145,21 @@ impl Solver<'_, usize> for Solution {
             let below = (sand_x, sand_y + 1);
             debug!("Sand: {:?}", sand_pos);
 
-            if sand_positions.contains(&start_pos) {
+            if blocked.get(start_pos) {
                 // Sand can no longer fall
                 break;
             }
-            sand_pos = if combined.structure.contains(&below)
-                || sand_positions.contains(&below)
-                || below.1 >= infinite_wall_height
-            {
+            sand_pos = if blocked.get(below) || below.1 >= infinite_wall_height {
                 // Sand is blocked, time to check if it can flow left or right
                 let left = (sand_x - 1, sand_y + 1);
                 let right = (sand_x + 1, sand_y + 1);
-                if combined.structure.contains(&left)
-                    || sand_positions.contains(&left)
-                    || left.1 >= infinite_wall_height
-                {
+                if blocked.get(left) || left.1 >= infinite_wall_height {
                     // Sand is blocked on the left, check if it can flow right
-                    if combined.structure.contains(&right)
-                        || sand_positions.contains(&right)
-                        || right.1 >= infinite_wall_height
-                    {
+                    if blocked.get(right) || right.1 >= infinite_wall_height {
                         // Sand is blocked on the right, rest and reset
-                        sand_positions.insert(sand_pos);
+                        blocked.set(sand_pos, true);
+                        sand += 1;
+                        record(&rock, &blocked);
                         start_pos
                     } else {
                         // Sand can flow right
@@ -153,22 +174,25 @@ impl Solver<'_, usize> for Solution {
             }
         }
 
-        sand_positions.len()
+        sand
     }
 }
 
 fn main() {
-    let sample = include_str!("../../samples/14.txt");
-    let input = include_str!("../../inputs/14.txt");
+    let file = "14.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 24),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 24),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 93),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 93),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 14),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
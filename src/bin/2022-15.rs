@@ -30,7 +30,7 @@ fn manhattan_distance(a: (i64, i64), b: (i64, i64)) -> i64 {
 struct Solution {}
 impl Solver<'_, usize> for Solution {
     fn solve_part_one(&self, lines: &[&str]) -> usize {
-        let target = if lines.len() == 14 { 10 } else { 2000000 };
+        let target = aoc::param("row").unwrap_or(if lines.len() == 14 { 10 } else { 2000000 });
         let sensors: HashMap<Sensor, Beacon> = lines.iter().map(|line| read_line(line)).collect();
         let beacons: HashSet<Beacon> = sensors.values().cloned().collect();
 
@@ -69,7 +69,7 @@ impl Solver<'_, usize> for Solution {
     }
 
     fn solve_part_two(&self, lines: &[&str]) -> usize {
-        let target = if lines.len() == 14 { 20 } else { 4000000 };
+        let target = aoc::param("limit").unwrap_or(if lines.len() == 14 { 20 } else { 4000000 });
         let sensors: HashMap<Sensor, (Beacon, i64)> = lines
             .iter()
             .map(|line| {
@@ -117,17 +117,20 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/15.txt");
-    let input = include_str!("../../inputs/15.txt");
+    let file = "15.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 26),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 26),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 56000011),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 56000011),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 15),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
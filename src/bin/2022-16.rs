@@ -1,12 +1,13 @@
 #![feature(iter_collect_into)]
 
-use aoc::{Graph, Solver};
+use aoc::{Graph, Repl, Solver};
 use bimap::BiMap;
 
 use itertools::Itertools;
 use log::debug;
 use std::{
     collections::{HashMap, HashSet},
+    rc::Rc,
     vec,
 };
 
@@ -360,20 +361,73 @@ impl Solver<'_, usize> for Solution {
             .max()
             .unwrap()
     }
+
+    fn register_commands<'l>(&self, lines: &'l [&'l str], repl: &mut Repl<'l>) {
+        let parsed = Rc::new(parse_lines(lines));
+
+        let state = parsed.clone();
+        repl.command(
+            "dist",
+            "dist <from> <to>: minutes to walk between valves",
+            move |args| {
+                let (graph, name_map, _) = &*state;
+                let [from, to] = args else {
+                    return Err("Expected two valves".to_string());
+                };
+                let from = name_map
+                    .get_by_left(from)
+                    .ok_or(format!("Unknown valve {from}"))?;
+                let to = name_map
+                    .get_by_left(to)
+                    .ok_or(format!("Unknown valve {to}"))?;
+                Ok(graph
+                    .all_distances(from)
+                    .get(to)
+                    .map_or("unreachable".to_string(), |d| d.to_string()))
+            },
+        );
+
+        let state = parsed;
+        repl.command(
+            "valve",
+            "valve <name>: flow rate and tunnels",
+            move |args| {
+                let (graph, name_map, flow_rates) = &*state;
+                let name = args.first().ok_or("Missing valve")?;
+                let valve = name_map
+                    .get_by_left(name)
+                    .ok_or(format!("Unknown valve {name}"))?;
+                let tunnels = graph
+                    .get(valve)
+                    .into_iter()
+                    .flat_map(|edges| edges.keys())
+                    .map(|v| name_map.get_by_right(v).unwrap())
+                    .sorted()
+                    .join(", ");
+                Ok(format!(
+                    "rate={}, tunnels to {}",
+                    flow_rates[valve], tunnels
+                ))
+            },
+        );
+    }
 }
 
 fn main() {
-    let sample = include_str!("../../samples/16.txt");
-    let input = include_str!("../../inputs/16.txt");
+    let file = "16.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 1651),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 1651),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 1707),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 1707),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 16),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
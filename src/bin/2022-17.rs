@@ -1,4 +1,4 @@
-use aoc::Solver;
+use aoc::{Cycle, CycleDetector, Recorder, Solver};
 use itertools::Itertools;
 use log::{debug, info};
 use std::collections::HashMap;
@@ -62,11 +62,12 @@ struct Cave {
     fallen_rocks: HashSet<Point>,
     height: usize,
     column_offsets: ColumnOffsets,
-    extra_height: usize,
     pushers: Vec<Push>,
     pusher_idx: usize,
     shapes: [(RockShape, Vec<Point>); 5],
-    cache: hashbrown::HashMap<(RockShape, usize, ColumnOffsets), (usize, usize)>,
+    /// Heights after each rock, keyed by everything that decides how the
+    /// following rocks fall
+    cycles: CycleDetector<(RockShape, usize, ColumnOffsets)>,
 }
 
 impl Cave {
@@ -75,15 +76,16 @@ impl Cave {
             fallen_rocks: HashSet::new(),
             height: 0,
             column_offsets: [None; 7],
-            extra_height: 0,
             pushers,
             pusher_idx: 0,
             shapes: RockShape::shapes(),
-            cache: hashbrown::HashMap::new(),
+            cycles: CycleDetector::new(),
         }
     }
 
-    fn simulate_rock(&mut self, rock_idx: usize, target_rocks: usize) -> usize {
+    /// Drops rock number `rock_idx`, returning the cycle once the tower
+    /// starts repeating.
+    fn simulate_rock(&mut self, rock_idx: usize) -> Option<Cycle> {
         let (shape, points) = &self.shapes[rock_idx % 5];
         let mut points = points
             .iter()
@@ -171,36 +173,18 @@ impl Cave {
                         rock_idx, self.height, points
                     );
                     self.fallen_rocks.extend(points);
-
-                    // return rock_idx + 1;
-
-                    let cache_key = (*shape, self.pusher_idx, self.column_offsets);
-                    let new_rock_idx = rock_idx
-                        + if let Some((cache_idx, cache_height)) = self.cache.get(&cache_key) {
-                            let repeats =
-                                (target_rocks - cache_idx - 1) / (rock_idx - cache_idx) - 1;
-                            self.extra_height += (self.height - cache_height) * repeats;
-                            info!(
-                                "Rock {} Cache hit for {:?} => ({}, {}), repeating {} times",
-                                rock_idx, cache_key, cache_idx, cache_height, repeats
-                            );
-                            (rock_idx - cache_idx) * repeats + 1
-                        } else {
-                            info!(
-                                "Rock {} Cache miss for {:?}, inserting ({}, {})",
-                                rock_idx, cache_key, rock_idx, self.height
-                            );
-                            self.cache.insert(cache_key, (rock_idx, self.height));
-                            1
-                        };
-                    debug!(
-                        "Rock {} height is {}, total height is {}, next: {}",
-                        rock_idx,
-                        self.height,
-                        self.get_total_height(),
-                        new_rock_idx
-                    );
-                    return new_rock_idx;
+                    self.record();
+
+                    let key = (*shape, self.pusher_idx, self.column_offsets);
+                    let cycle = self.cycles.record(key, self.height as i64);
+                    if let Some(cycle) = cycle {
+                        info!(
+                            "Rock {} repeats rock {}, every {} rocks",
+                            rock_idx, cycle.start, cycle.period
+                        );
+                    }
+                    debug!("Rock {} height is {}", rock_idx, self.height);
+                    return cycle;
                 }
             }
         }
@@ -229,6 +213,17 @@ impl Cave {
         debug!("+{}+", "-".repeat(7));
     }
 
+    /// Pushes the top of the tower as a recording frame, with up drawn up.
+    fn record(&self) {
+        let rows = 40;
+        Recorder::points(
+            self.fallen_rocks
+                .iter()
+                .filter(|(_, y)| y + rows >= self.height)
+                .map(|(x, y)| ((*x, self.height - 1 - y), '#')),
+        );
+    }
+
     fn cull_rocks(&mut self) {
         let offset = 100;
         if self.fallen_rocks.len() > 1000 && self.height > 2 * offset {
@@ -237,8 +232,16 @@ impl Cave {
         }
     }
 
-    fn get_total_height(&self) -> usize {
-        self.height + self.extra_height
+    /// Height of the tower after `rock_count` rocks.
+    fn height_after(&mut self, rock_count: usize) -> usize {
+        for rock_idx in 0..rock_count {
+            if self.simulate_rock(rock_idx).is_some() {
+                return self.cycles.value_at(rock_count - 1).unwrap() as usize;
+            }
+            self.cull_rocks();
+            // self.draw()
+        }
+        self.height
     }
 }
 
@@ -248,51 +251,35 @@ impl Solver<'_, usize> for Solution {
         let line = lines[0];
         let pushers = line.chars().map(Push::from_char).collect_vec();
 
-        let mut cave = Cave::new(pushers);
-        let rock_count = 2022;
-        let mut rock_idx = 0;
-        while rock_idx < rock_count {
-            rock_idx = cave.simulate_rock(rock_idx, rock_count);
-            cave.cull_rocks();
-            // cave.draw()
-        }
-        info!("Done, rock_idx = {}", rock_idx);
-
-        cave.get_total_height()
+        Cave::new(pushers).height_after(2022)
     }
 
     fn solve_part_two(&self, lines: &[&str]) -> usize {
         let line = lines[0];
         let pushers = line.chars().map(Push::from_char).collect_vec();
 
-        let final_rock_count: usize = 1000000000000; // one trillion
-
-        let mut cave = Cave::new(pushers);
-        let mut rock_idx = 0;
-
-        while rock_idx < final_rock_count {
-            rock_idx = cave.simulate_rock(rock_idx, final_rock_count);
-            cave.cull_rocks();
-        }
-        cave.get_total_height()
         // 1565242165215 is too high
         // 1565242165189 is too low
         // 1565242165191 is too low
+        Cave::new(pushers).height_after(1000000000000) // one trillion
     }
 }
 
 fn main() {
-    let sample = include_str!("../../samples/17.txt");
-    let input = include_str!("../../inputs/17.txt");
+    let file = "17.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 3068),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 3068),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 1514285714288),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 1514285714288),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 17),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -0,0 +1,91 @@
+use aoc::{BitGrid3, Bounds3, Grid3, Point3, Solver};
+use itertools::Itertools;
+use log::debug;
+use std::collections::HashSet;
+
+fn parse_cube(s: &str) -> Point3 {
+    let (x, y, z) = s
+        .split(',')
+        .map(|s| s.parse().unwrap())
+        .collect_tuple()
+        .unwrap();
+    Point3::new(x, y, z)
+}
+
+/// Faces not touching another cube, counted a whole side at a time: shifting
+/// the droplet against itself leaves the cubes with nothing on that side.
+fn count_surface_area(cubes: &BitGrid3) -> usize {
+    Point3::default()
+        .neighbors6()
+        .map(|side| {
+            let mut exposed = !&cubes.shift(-side);
+            exposed &= cubes;
+            exposed.count_ones()
+        })
+        .sum()
+}
+
+fn count_surface_area_outer_only(
+    cubes: &HashSet<Point3>,
+    surrounding_air: &HashSet<Point3>,
+) -> usize {
+    cubes.iter().fold(0, |mut acc, cube| {
+        let air = cube.neighbors6().filter(|c| surrounding_air.contains(c));
+
+        acc += air.count();
+        acc
+    })
+}
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let cubes: HashSet<Point3> = lines.iter().map(|line| parse_cube(line)).collect();
+        debug!("{:?}", cubes);
+        let bounds = Bounds3::from_points(cubes.iter().copied()).unwrap();
+        let lava = Grid3::from_points(bounds, cubes.iter().copied(), true);
+        for (idx, component) in lava.components(|lava| *lava).iter().enumerate() {
+            debug!("Found component {idx} {:?}", component);
+        }
+
+        count_surface_area(&BitGrid3::from_points(bounds, cubes.iter().copied()))
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let cubes: HashSet<Point3> = lines.iter().map(|line| parse_cube(line)).collect();
+
+        // Leave a layer of air around the droplet so the flood fill can reach every side
+        let bounds = Bounds3::from_points(cubes.iter().copied()).unwrap().grow(1);
+        debug!("{:?}", bounds);
+
+        let lava = Grid3::from_points(bounds, cubes.iter().copied(), true);
+        let surrounding_air = lava.flood_fill(bounds.min, |lava| !lava);
+
+        count_surface_area_outer_only(&cubes, &surrounding_air)
+    }
+}
+
+fn main() {
+    let file = "18.txt";
+    let sample_2 = "18_2.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 64),
+        aoc::Input::sample_file(sample_2, 10),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 58),
+        aoc::Input::final_file(file),
+        // 2002 is too low
+        // 2004 is too low
+        // 2006 is too low
+        // Too low means overcounting air pockets?
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 18),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
@@ -305,17 +305,20 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/19.txt");
-    let input = include_str!("../../inputs/19.txt");
+    let file = "19.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 33),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 33),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 3472),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 3472),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 19),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
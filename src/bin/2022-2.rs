@@ -1,20 +1,23 @@
 use aoc::Solver;
 use itertools::Itertools;
 fn main() {
-    let sample = include_str!("../../samples/2.txt");
-    let input = include_str!("../../inputs/2.txt");
+    let file = "2.txt";
 
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 15),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 15),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 12),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 12),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 2),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
 
 enum Outcome {
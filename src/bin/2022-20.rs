@@ -185,17 +185,20 @@ impl Solver<'_, i64> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/20.txt");
-    let input = include_str!("../../inputs/20.txt");
+    let file = "20.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 3),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 3),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 1623178306),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 1623178306),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 20),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -1,6 +1,7 @@
 use std::fmt::Display;
+use std::rc::Rc;
 
-use aoc::Solver;
+use aoc::{Repl, Solver};
 use itertools::Itertools;
 use log::{debug, error, info};
 use std::cmp::Ordering;
@@ -231,20 +232,56 @@ impl Solver<'_, i128> for Solution {
         error!("Failed to find a solution");
         Default::default()
     }
+
+    fn register_commands<'l>(&self, lines: &'l [&'l str], repl: &mut Repl<'l>) {
+        let monkeys = Rc::new(parse_lines(lines));
+
+        let map = monkeys.clone();
+        repl.command(
+            "eval",
+            "eval <monkey> [humn]: number the monkey yells",
+            move |args| {
+                let monkey = args.first().ok_or("Missing monkey")?;
+                let mut map = (*map).clone();
+                if let Some(human) = args.get(1) {
+                    let human = human.parse().map_err(|_| format!("Bad number {human}"))?;
+                    *map.get_mut("humn").ok_or("No humn monkey")? = Monkey::Constant(human);
+                }
+                let monkey = map.get(*monkey).ok_or(format!("Unknown monkey {monkey}"))?;
+                Ok(process(monkey, &map, 0).to_string())
+            },
+        );
+
+        let map = monkeys;
+        repl.command(
+            "expr",
+            "expr <monkey>: simplified expression with humn as x",
+            move |args| {
+                let monkey = args.first().ok_or("Missing monkey")?;
+                let mut map = (*map).clone();
+                *map.get_mut("humn").ok_or("No humn monkey")? = Monkey::Human;
+                let monkey = map.get(*monkey).ok_or(format!("Unknown monkey {monkey}"))?;
+                Ok(to_expression(monkey, &map).simplify().to_string())
+            },
+        );
+    }
 }
 
 fn main() {
-    let sample = include_str!("../../samples/21.txt");
-    let input = include_str!("../../inputs/21.txt");
+    let file = "21.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 152),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 152),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 301),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 301),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 21),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -0,0 +1,441 @@
+use std::fmt::Display;
+
+use aoc::{CubeNet, Direction, Solver};
+use itertools::Itertools;
+
+use aoc::{Grid, Render, Style, Wrap};
+use log::{debug, info};
+
+fn score(direction: Direction) -> usize {
+    match direction {
+        Direction::Up => 3,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Right => 0,
+        _ => panic!("Unexpected direction {}", direction),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    Empty,
+    Wall,
+    Open(Option<Direction>),
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Tile::Empty
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Tile::Empty => ' ',
+                Tile::Wall => '#',
+                Tile::Open(None) => '.',
+                Tile::Open(Some(Direction::Up)) => '^',
+                Tile::Open(Some(Direction::Down)) => 'v',
+                Tile::Open(Some(Direction::Left)) => '<',
+                Tile::Open(Some(Direction::Right)) => '>',
+                Tile::Open(Some(dir)) => panic!("Unexpected direction {}", dir),
+            }
+        )
+    }
+}
+
+/// Renders the map around `pos`, with the walked path highlighted.
+fn render(grid: &Grid<Tile>, pos: (usize, usize)) -> Render<'_, Tile> {
+    grid.render(move |at, tile| match tile {
+        _ if at == pos => Style::PLAIN.bg([200, 40, 40]).bold(),
+        Tile::Wall => Style::PLAIN.fg([110, 110, 110]),
+        Tile::Open(Some(_)) => Style::PLAIN.fg([80, 200, 255]).bold(),
+        _ => Style::PLAIN,
+    })
+    .around(pos, 120, 60)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Command {
+    Clockwise,
+    Counterclockwise,
+    Move(usize),
+}
+
+/// The map, the starting position and the path to follow.
+fn parse(lines: &[&str]) -> (Grid<Tile>, (usize, usize), Vec<Command>) {
+    let (grid_str, commands_str) = lines.split(|s| s.is_empty()).collect_tuple().unwrap();
+
+    let (grid, markers) = Grid::try_from_lines_with_markers(
+        grid_str,
+        Some(Tile::Empty),
+        &[('.', Tile::Open(None))],
+        |c| match c {
+            '#' => Ok(Tile::Wall),
+            ' ' => Ok(Tile::Empty),
+            _ => Err("unexpected character"),
+        },
+    )
+    .unwrap();
+    // The path starts on the leftmost open tile of the top row
+    let start = markers.first('.').unwrap();
+
+    debug!("Grid with dimensions ({}, {})", grid.width, grid.height);
+
+    assert_eq!(commands_str.len(), 1);
+    let command_str = commands_str[0].chars().collect_vec();
+    let mut commands = Vec::new();
+
+    let mut idx = 0;
+    let mut temp = String::new();
+
+    let f = |temp: &mut String, commands: &mut Vec<Command>| {
+        if !temp.is_empty() {
+            commands.push(Command::Move(temp.parse().unwrap()));
+            temp.clear();
+        }
+    };
+
+    while idx < command_str.len() {
+        match command_str[idx] {
+            'R' => {
+                f(&mut temp, &mut commands);
+                commands.push(Command::Clockwise);
+            }
+            'L' => {
+                f(&mut temp, &mut commands);
+                commands.push(Command::Counterclockwise);
+            }
+            '0'..='9' => {
+                temp.push(command_str[idx]);
+            }
+            _ => panic!("Unexpected character: {}", command_str[idx]),
+        }
+        idx += 1;
+    }
+    f(&mut temp, &mut commands);
+
+    (grid, start, commands)
+}
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let (mut grid, (mut current_col, mut current_row), commands) = parse(lines);
+
+        let mut current_dir = Direction::Right;
+
+        debug!("Starting at ({}, {})", current_col, current_row);
+
+        // Blank cells are not part of the map, so walking onto one continues past it
+        let wrap = Wrap::skip(|tile: &Tile| *tile == Tile::Empty);
+
+        for command in commands {
+            match command {
+                Command::Clockwise => current_dir = current_dir.rotate(true),
+                Command::Counterclockwise => current_dir = current_dir.rotate(false),
+                Command::Move(n) => {
+                    for _ in 0..n {
+                        let next = grid
+                            .step(
+                                (current_col, current_row),
+                                current_dir.offset().into(),
+                                &wrap,
+                            )
+                            .unwrap();
+                        if *grid.at(next) != Tile::Wall {
+                            (current_col, current_row) = next;
+                        }
+                        *grid.mut_at((current_col, current_row)) = Tile::Open(Some(current_dir));
+                    }
+                }
+            }
+            debug!(
+                "({}, {}) with direction {:?} after command {command:?}",
+                current_col, current_row, current_dir
+            );
+        }
+
+        info!(
+            "Final position: ({}, {}) with direction {}",
+            current_col, current_row, current_dir
+        );
+        debug!("Path {}", render(&grid, (current_col, current_row)));
+
+        let final_col = current_col + 1;
+        let final_row = current_row + 1;
+
+        1000 * final_row + 4 * final_col + score(current_dir)
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let (mut grid, (mut current_col, mut current_row), commands) = parse(lines);
+
+        let cube = CubeNet::from_grid(&grid, |tile| *tile == Tile::Empty).unwrap();
+        debug!("Cube with faces of size {}", cube.size());
+
+        let mut current_dir = Direction::Right;
+
+        debug!("Starting at ({}, {})", current_col, current_row);
+
+        *grid.mut_at((current_col, current_row)) = Tile::Open(Some(current_dir));
+        debug!(
+            "Original grid {}",
+            render(&grid, (current_col, current_row))
+        );
+
+        for command in commands {
+            match command {
+                Command::Clockwise => {
+                    current_dir = current_dir.rotate(true);
+                }
+                Command::Counterclockwise => {
+                    current_dir = current_dir.rotate(false);
+                }
+                Command::Move(n) => {
+                    for _ in 0..n {
+                        let (next, next_dir) = cube.step((current_col, current_row), current_dir);
+                        if next_dir != current_dir {
+                            debug!(
+                                "Wrapping around cube: ({}, {}) with direction {:?} to {:?} with direction {:?}",
+                                current_col, current_row, current_dir, next, next_dir
+                            );
+                        }
+                        if *grid.at(next) != Tile::Wall {
+                            (current_col, current_row) = next;
+                            current_dir = next_dir;
+                        }
+
+                        *grid.mut_at((current_col, current_row)) = Tile::Open(Some(current_dir));
+                    }
+                }
+            }
+
+            debug!(
+                "({}, {}) with direction {:?} after command {command:?}",
+                current_col, current_row, current_dir
+            );
+        }
+
+        info!(
+            "Final position: ({}, {}) with direction {}",
+            current_col, current_row, current_dir
+        );
+        debug!("Path {}", render(&grid, (current_col, current_row)));
+
+        let final_col = current_col + 1;
+        let final_row = current_row + 1;
+
+        1000 * final_row + 4 * final_col + score(current_dir)
+    }
+}
+
+fn main() {
+    let file = "22.txt";
+    let sample_2 = "22_1.txt";
+    let sample_3 = "22_2.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 6032),
+        aoc::Input::sample_file(sample_2, 1038),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 5031),
+        aoc::Input::sample_file(sample_2, 12056),
+        aoc::Input::sample_file(sample_3, 1023),
+        aoc::Input::final_file(file), // 93373 too low // 103134 //189097
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 22),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The net of the sample map, with faces of size 4.
+    fn sample_cube() -> CubeNet {
+        CubeNet::from_faces(4, &[(2, 0), (0, 1), (1, 1), (2, 1), (2, 2), (3, 2)]).unwrap()
+    }
+
+    #[test]
+    fn test_wrap_around_top_mid() {
+        let cube = sample_cube();
+        // Off top mid
+        assert_eq!(cube.step((8, 0), Direction::Up), ((3, 4), Direction::Down));
+        assert_eq!(cube.step((10, 0), Direction::Up), ((1, 4), Direction::Down));
+        assert_eq!(cube.step((11, 0), Direction::Up), ((0, 4), Direction::Down));
+    }
+
+    #[test]
+    fn test_wrap_around_bottom_mid() {
+        let cube = sample_cube();
+        // Off bottom mid
+        assert_eq!(cube.step((8, 11), Direction::Down), ((3, 7), Direction::Up));
+        assert_eq!(
+            cube.step((10, 11), Direction::Down),
+            ((1, 7), Direction::Up)
+        );
+        assert_eq!(
+            cube.step((11, 11), Direction::Down),
+            ((0, 7), Direction::Up)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_bottom_right() {
+        let cube = sample_cube();
+        // Off bottom right
+        assert_eq!(
+            cube.step((12, 11), Direction::Down),
+            ((0, 7), Direction::Right)
+        );
+        assert_eq!(
+            cube.step((15, 11), Direction::Down),
+            ((0, 4), Direction::Right)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_left() {
+        let cube = sample_cube();
+        // Off left
+        assert_eq!(
+            cube.step((0, 4), Direction::Left),
+            ((15, 11), Direction::Up)
+        );
+        assert_eq!(
+            cube.step((0, 6), Direction::Left),
+            ((13, 11), Direction::Up)
+        );
+        assert_eq!(
+            cube.step((0, 7), Direction::Left),
+            ((12, 11), Direction::Up)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_right() {
+        let cube = sample_cube();
+        // Off right
+        assert_eq!(
+            cube.step((15, 8), Direction::Right),
+            ((11, 3), Direction::Left)
+        );
+        assert_eq!(
+            cube.step((15, 10), Direction::Right),
+            ((11, 1), Direction::Left)
+        );
+        assert_eq!(
+            cube.step((15, 11), Direction::Right),
+            ((11, 0), Direction::Left)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_mid_top_right() {
+        let cube = sample_cube();
+        assert_eq!(
+            cube.step((11, 0), Direction::Right),
+            ((15, 11), Direction::Left)
+        );
+        assert_eq!(
+            cube.step((11, 3), Direction::Right),
+            ((15, 8), Direction::Left)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_mid_mid_right() {
+        let cube = sample_cube();
+        assert_eq!(
+            cube.step((11, 4), Direction::Right),
+            ((15, 8), Direction::Down)
+        );
+        assert_eq!(
+            cube.step((11, 6), Direction::Right),
+            ((13, 8), Direction::Down)
+        );
+        assert_eq!(
+            cube.step((11, 7), Direction::Right),
+            ((12, 8), Direction::Down)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_bottom_right_up() {
+        let cube = sample_cube();
+        assert_eq!(
+            cube.step((12, 8), Direction::Up),
+            ((11, 7), Direction::Left)
+        );
+        assert_eq!(
+            cube.step((15, 8), Direction::Up),
+            ((11, 4), Direction::Left)
+        );
+    }
+
+    #[test]
+    fn test_wrap_around_bottom_mid_left() {
+        let cube = sample_cube();
+        assert_eq!(cube.step((8, 8), Direction::Left), ((7, 7), Direction::Up));
+        assert_eq!(cube.step((8, 10), Direction::Left), ((5, 7), Direction::Up));
+        assert_eq!(cube.step((8, 11), Direction::Left), ((4, 7), Direction::Up));
+    }
+    #[test]
+    fn test_wrap_around_top_left() {
+        let cube = sample_cube();
+        assert_eq!(
+            cube.step((8, 0), Direction::Left),
+            ((4, 4), Direction::Down)
+        );
+        assert_eq!(
+            cube.step((8, 3), Direction::Left),
+            ((7, 4), Direction::Down)
+        );
+    }
+    #[test]
+    fn test_wrap_around_mid_left_up() {
+        let cube = sample_cube();
+        assert_eq!(cube.step((0, 4), Direction::Up), ((11, 0), Direction::Down));
+        assert_eq!(cube.step((3, 4), Direction::Up), ((8, 0), Direction::Down));
+    }
+    #[test]
+    fn test_wrap_around_mid_mid_left_up() {
+        let cube = sample_cube();
+        assert_eq!(cube.step((4, 4), Direction::Up), ((8, 0), Direction::Right));
+        assert_eq!(cube.step((7, 4), Direction::Up), ((8, 3), Direction::Right));
+    }
+
+    #[test]
+    fn test_wrap_around_mid_left_down() {
+        let cube = sample_cube();
+        assert_eq!(
+            cube.step((0, 7), Direction::Down),
+            ((11, 11), Direction::Up)
+        );
+        assert_eq!(cube.step((3, 7), Direction::Down), ((8, 11), Direction::Up));
+    }
+
+    #[test]
+    fn test_wrap_around_mid_mid_left_down() {
+        let cube = sample_cube();
+        assert_eq!(
+            cube.step((4, 7), Direction::Down),
+            ((8, 11), Direction::Right)
+        );
+        assert_eq!(
+            cube.step((7, 7), Direction::Down),
+            ((8, 8), Direction::Right)
+        );
+    }
+}
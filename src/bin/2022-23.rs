@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc::{Automaton, Cell, Connectivity, Direction, Point, Recorder, Solver, SparseGrid};
+use log::{debug, info, log_enabled, Level};
+
+type Elves = SparseGrid<()>;
+
+struct Board {
+    elves: Automaton<Elves>,
+}
+
+/// The cells that must be free of elves to move `direction`.
+fn deltas(direction: Direction) -> [Point; 3] {
+    let ahead = direction.offset();
+    [
+        ahead,
+        ahead + direction.turn_left(),
+        ahead + direction.turn_right(),
+    ]
+}
+
+/// The order elves consider moving in during the first round.
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        let elves = &self.elves.space;
+        let Some((min, max)) = elves.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if elves.contains((x, y)) {
+                    write!(f, "🟦")?;
+                } else {
+                    write!(f, "⬛️")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Where `elf` proposes to move in `round`.
+fn proposal(elves: &Elves, elf: Point, round: usize) -> Option<Point> {
+    let occupied = |offset: Point| elves.contains(elf + offset);
+    let mut neighbors = DIRECTIONS.iter().flat_map(|direction| deltas(*direction));
+    if !neighbors.any(occupied) {
+        return None;
+    }
+    DIRECTIONS
+        .iter()
+        .cycle()
+        .skip(round - 1)
+        .take(4)
+        .find(|&&direction| !deltas(direction).iter().copied().any(occupied))
+        .map(|&direction| elf + direction)
+}
+
+fn record(elves: &Elves) {
+    Recorder::points(elves.positions().map(|pos| (pos, '#')));
+}
+
+/// The proposals of one round, worked out once before the cells are updated.
+struct Plan {
+    proposals: HashMap<Point, Point>,
+    proposers: HashMap<Point, usize>,
+}
+
+impl Plan {
+    fn new(elves: &Elves, round: usize) -> Self {
+        let proposals: HashMap<_, _> = elves
+            .positions()
+            .filter_map(|elf| proposal(elves, elf, round).map(|target| (elf, target)))
+            .collect();
+        let mut proposers = HashMap::new();
+        for target in proposals.values() {
+            *proposers.entry(*target).or_insert(0) += 1;
+        }
+        Plan {
+            proposals,
+            proposers,
+        }
+    }
+
+    /// An elf moves to the cell it proposes unless another elf proposes the
+    /// same cell, so a cell is occupied next round if its elf stays or
+    /// exactly one elf moves into it.
+    fn rule(&self, cell: &Cell<Elves>) -> Option<()> {
+        let only_proposer = |target: &Point| self.proposers.get(target) == Some(&1);
+        match cell.value() {
+            Some(()) => match self.proposals.get(&cell.pos()) {
+                Some(target) if only_proposer(target) => None,
+                _ => Some(()),
+            },
+            None => only_proposer(&cell.pos()).then_some(()),
+        }
+    }
+}
+
+impl Board {
+    fn from_lines(lines: &[&str]) -> Self {
+        let elves = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars().enumerate().filter_map(move |(x, c)| match c {
+                    '#' => Some((Point::from((x, y)), ())),
+                    _ => None,
+                })
+            })
+            .collect();
+        record(&elves);
+        Self {
+            elves: Automaton::new(elves, Connectivity::Eight),
+        }
+    }
+
+    fn count_empty_spaces(&self) -> usize {
+        self.elves.space.count_empty()
+    }
+
+    /// Plays the next round, returning how many elves moved.
+    fn simulate(&mut self) -> usize {
+        let plan = Plan::new(&self.elves.space, self.elves.generation() + 1);
+        // Every move empties one cell and fills another
+        let moved_elf_count = self.elves.step(&|cell| plan.rule(cell)) / 2;
+        record(&self.elves.space);
+        moved_elf_count
+    }
+}
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let mut board = Board::from_lines(lines);
+        info!("{}", board);
+
+        for round in 1..=10 {
+            let before = log_enabled!(Level::Debug).then(|| board.elves.space.clone());
+            let moved = board.simulate();
+            info!("Round {}: {} elves moved", round, moved);
+            if let Some(before) = before {
+                debug!("{}", before.side_by_side(&board.elves.space, |_| '#'));
+            }
+        }
+        board.count_empty_spaces()
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let mut board = Board::from_lines(lines);
+        info!("{}", board);
+
+        let rounds = board.elves.run_until_stable(
+            |elves| {
+                if let Some(changes) = elves.changes.last() {
+                    info!("Round {}: {} elves moved", elves.generation(), changes / 2);
+                    record(&elves.space);
+                }
+                Plan::new(&elves.space, elves.generation() + 1)
+            },
+            Plan::rule,
+        );
+        info!("Round {}: no elves moved", rounds);
+        debug!("{}", board);
+        rounds
+    }
+}
+
+fn main() {
+    let file = "23.txt";
+    let sample_2 = "23_1.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 110),
+        aoc::Input::sample_file(sample_2, 25),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 20),
+        aoc::Input::final_file(file),
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 23),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
@@ -0,0 +1,273 @@
+use std::convert::TryFrom;
+
+use aoc::{Cycle, Direction, Grid, Recorder, Solver, Wrap};
+use hashbrown::{HashMap, HashSet};
+use log::debug;
+
+type Position = (isize, isize);
+/// Directions of the blizzards in each occupied cell, sorted so equal
+/// valleys compare equal
+type Blizzards = HashMap<Position, Vec<Direction>>;
+
+#[derive(Debug)]
+struct State {
+    walls: Grid<bool>,
+    /// The ground inside the walls
+    valley: Grid<bool>,
+    /// The blizzards at every minute of their period reached so far
+    valleys: Vec<Blizzards>,
+    cycle: Cycle,
+    minute: usize,
+    player: Position,
+    goal: Position,
+    x_range: (isize, isize),
+    y_range: (isize, isize),
+}
+
+impl State {
+    fn from_lines(lines: &[&str]) -> Self {
+        let x_range = (1_isize, lines[0].len() as isize - 2);
+        let y_range = (1_isize, lines.len() as isize - 2);
+
+        // The only gaps in the walls are the entrance at the top and the exit
+        // at the bottom, so they are the first and last open cells
+        let (walls, markers) = Grid::from_lines_with_markers(lines, &[('.', false)], &|c| c == '#');
+        let to_signed = |(x, y): (usize, usize)| (x as isize, y as isize);
+        let player = to_signed(markers.first('.').unwrap());
+        let goal = to_signed(markers.last('.').unwrap());
+        let mut blizzards = HashMap::new();
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                // Walls and open ground are `#` and `.`, blizzards `^v<>`
+                if let Ok(dir) = Direction::try_from(char) {
+                    blizzards
+                        .entry((x as isize, y as isize))
+                        .or_insert_with(Vec::new)
+                        .push(dir);
+                }
+            }
+        }
+
+        // Every blizzard keeps moving in a loop, so the valley as a whole
+        // repeats from the start, with period lcm(width, height)
+        let valley = valley(&walls);
+        let cycle = Cycle {
+            start: 0,
+            period: num::integer::lcm(valley.width, valley.height),
+        };
+        debug!("Blizzards repeat every {} minutes", cycle.period);
+
+        State {
+            walls,
+            valley,
+            valleys: vec![blizzards],
+            cycle,
+            minute: 0,
+            player,
+            goal,
+            x_range,
+            y_range,
+        }
+    }
+
+    fn in_bounds(&self, pos: Position) -> bool {
+        let (x, y) = pos;
+        let (min_x, max_x) = self.x_range;
+        let (min_y, max_y) = self.y_range;
+
+        x >= min_x && x <= max_x && y >= min_y && y <= max_y
+    }
+
+    /// Moves the blizzards on to the current minute, the first time the
+    /// minute's place in their period is reached.
+    fn update_blizzards(&mut self) {
+        while self.valleys.len() <= self.cycle.index(self.minute) {
+            let next = new_blizzards(&self.valley, self.valleys.last().unwrap());
+            self.valleys.push(next);
+        }
+    }
+
+    fn blizzards(&self) -> &Blizzards {
+        &self.valleys[self.cycle.index(self.minute)]
+    }
+
+    /// Pushes the valley as a recording frame, with every position the
+    /// expedition could be in marked `E`.
+    fn record(&self, branches: &HashSet<Position>) {
+        if !Recorder::is_active() {
+            return;
+        }
+        let walls = self
+            .walls
+            .enumerate()
+            .filter(|(_, wall)| **wall)
+            .map(|((x, y), _)| ((x as isize, y as isize), '#'));
+        let blizzards = self.blizzards().iter().map(|(pos, dirs)| {
+            let symbol = match dirs[..] {
+                [Direction::Up] => '^',
+                [Direction::Down] => 'v',
+                [Direction::Left] => '<',
+                [Direction::Right] => '>',
+                _ => char::from_digit(dirs.len() as u32, 10).unwrap_or('*'),
+            };
+            (*pos, symbol)
+        });
+        let branches = branches.iter().map(|pos| (*pos, 'E'));
+        Recorder::points(walls.chain(blizzards).chain(branches));
+    }
+
+    fn time_to_goal(&mut self) -> usize {
+        let start = self.minute;
+        let mut branches = HashSet::new();
+        branches.insert(self.player);
+
+        loop {
+            self.minute += 1;
+            self.update_blizzards();
+            let blizzards = self.blizzards();
+            let mut new_branches = HashSet::new();
+
+            for branch in branches.iter() {
+                if !blizzards.contains_key(branch) {
+                    let new_branch = *branch;
+                    new_branches.insert(new_branch); // Stay put
+                }
+
+                for direction in Direction::CARDINAL {
+                    let offset = direction.offset();
+                    let new_branch = (branch.0 + offset.x, branch.1 + offset.y);
+
+                    if new_branch == self.goal {
+                        return self.minute - start;
+                    }
+
+                    if self.in_bounds(new_branch) {
+                        if blizzards.contains_key(&new_branch) {
+                            // If the player moves into a blizzard, stop this branch
+                            continue;
+                        }
+
+                        new_branches.insert(new_branch);
+                    }
+                }
+            }
+
+            branches = new_branches;
+            self.record(&branches);
+        }
+    }
+}
+
+/// The ground inside the walls, which blizzards never leave.
+fn valley(walls: &Grid<bool>) -> Grid<bool> {
+    walls.crop((1, 1), walls.width - 2, walls.height - 2)
+}
+
+/// The blizzards one minute later, moving across `valley`.
+fn new_blizzards(valley: &Grid<bool>, blizzards: &Blizzards) -> Blizzards {
+    let mut new_blizzards = HashMap::new();
+    // Blizzards leaving the valley reappear on the far side of the opposite
+    // wall, even in the columns of the entrance and exit
+    let wrap = Wrap::Torus;
+
+    for (pos, dir) in blizzards {
+        for dir in dir {
+            let offset = dir.offset().into();
+            let (x, y) = valley
+                .step((pos.0 as usize - 1, pos.1 as usize - 1), offset, &wrap)
+                .unwrap();
+
+            new_blizzards
+                .entry((x as isize + 1, y as isize + 1))
+                .or_insert_with(Vec::new)
+                .push(*dir);
+        }
+    }
+
+    for dirs in new_blizzards.values_mut() {
+        dirs.sort();
+    }
+    new_blizzards
+}
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let mut state = State::from_lines(lines);
+
+        debug!("Initial state: {:?}", state);
+
+        state.time_to_goal()
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let mut state = State::from_lines(lines);
+        let start_point = state.player;
+        let end_point = state.goal;
+
+        debug!("Initial state: {:?}", state);
+        let first_run = state.time_to_goal();
+        debug!("Took {} minutes to reach the goal", first_run);
+
+        state.player = end_point;
+        state.goal = start_point;
+        let second_run = state.time_to_goal();
+        debug!("Took {} minutes to reach the start", second_run);
+
+        state.player = start_point;
+        state.goal = end_point;
+        let third_run = state.time_to_goal();
+        debug!("Took {} minutes to reach the goal", third_run);
+
+        first_run + second_run + third_run
+    }
+}
+
+fn main() {
+    let file = "24.txt";
+    let sample_2 = "24_1.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 18),
+        aoc::Input::sample_file(sample_2, 10),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 54),
+        aoc::Input::final_file(file), // 844 is too low // 846 is too low
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 24),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blizzards_wrap_past_gaps() {
+        let walls = Grid::from_lines(&["#.####", "#....#", "#....#", "####.#"], &|c| c == '#');
+        let valley = valley(&walls);
+        let blizzards: Blizzards = [
+            ((1, 1), vec![Direction::Up]),
+            ((4, 2), vec![Direction::Down]),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        // Neither leaves through the entrance above or the exit below
+        let moved: Blizzards = [
+            ((1, 2), vec![Direction::Up]),
+            ((4, 1), vec![Direction::Down]),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        assert_eq!(new_blizzards(&valley, &blizzards), moved);
+    }
+}
@@ -121,19 +121,22 @@ impl Solver<'_, String> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/25.txt");
-    let input = include_str!("../../inputs/25.txt");
+    let file = "25.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, "2=-1=0".to_owned()),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, "2=-1=0".to_owned()),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, Default::default()), // TODO: Fill in expected sample result
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, Default::default()), // TODO: Fill in expected sample result
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 25),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
 
 #[cfg(test)]
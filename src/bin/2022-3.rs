@@ -1,20 +1,23 @@
 use aoc::Solver;
 use im::HashSet;
 fn main() {
-    let sample = include_str!("../../samples/3.txt");
-    let input = include_str!("../../inputs/3.txt");
+    let file = "3.txt";
 
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 157),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 157),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 70),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 70),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 3),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
 
 fn find_common(line: &str) -> char {
@@ -58,17 +58,20 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/4.txt");
-    let input = include_str!("../../inputs/4.txt");
+    let file = "4.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 2),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 2),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 4),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 4),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 4),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
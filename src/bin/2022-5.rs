@@ -108,17 +108,20 @@ impl Solver<'_, String> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/5.txt");
-    let input = include_str!("../../inputs/5.txt");
+    let file = "5.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, "CMZ".to_owned()),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, "CMZ".to_owned()),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, "MCD".to_owned()),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, "MCD".to_owned()),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 5),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -39,25 +39,28 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/6.txt");
-    let input = include_str!("../../inputs/6.txt");
+    let file = "6.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 7),
+        aoc::Input::sample_file(file, 7),
         aoc::Input::new_sample("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
         aoc::Input::new_sample("nppdvjthqldpwncqszvftbrmjlhg", 6),
         aoc::Input::new_sample("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
         aoc::Input::new_sample("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
-        aoc::Input::new_final(input),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 19),
+        aoc::Input::sample_file(file, 19),
         aoc::Input::new_sample("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
         aoc::Input::new_sample("nppdvjthqldpwncqszvftbrmjlhg", 23),
         aoc::Input::new_sample("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
         aoc::Input::new_sample("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
-        aoc::Input::new_final(input),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 6),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -220,17 +220,20 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/7.txt");
-    let input = include_str!("../../inputs/7.txt");
+    let file = "7.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, 95437),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 95437),
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, 24933642),
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, 24933642),
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(
+        aoc::Day::new(2022, 7),
+        &part_one_problems,
+        &part_two_problems,
+    );
 }
@@ -0,0 +1,48 @@
+use aoc::Solver;
+use aoc::{Direction, Grid};
+use log::debug;
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let grid: Grid<u8> = Grid::from_lines(lines, &|c: char| c.to_digit(10).unwrap() as u8);
+        debug!("Grid: {}, {}, {}", grid.width, grid.height, grid);
+        let visible = grid.visible_from(&Direction::CARDINAL);
+        visible.state.iter().filter(|&&seen| seen).count()
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let grid: Grid<u8> = Grid::from_lines(lines, &|c: char| c.to_digit(10).unwrap() as u8);
+        grid.enumerate()
+            .map(|(p, val)| {
+                // Trees are seen up to and including the first one at least as tall
+                let scores = Direction::CARDINAL
+                    .map(|direction| grid.cast(p, direction, |tree| tree >= val).distance);
+
+                debug!("Value {val} at ({}, {}) -> {:?}", p.0, p.1, scores);
+
+                scores.iter().product::<usize>()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+fn main() {
+    let file = "8.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 21),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 8),
+        aoc::Input::final_file(file),
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 8),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
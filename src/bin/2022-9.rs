@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use aoc::{Direction, Point, Recorder, Solver};
+use itertools::Itertools;
+use log::debug;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+struct Command {
+    direction: Direction,
+    distance: usize,
+}
+
+fn move_by(position: &mut Point, command: &mut Command) -> bool {
+    *position = position.step(command.direction);
+    command.distance -= 1;
+    command.distance > 0
+}
+
+fn step_tail_closer(tail: &mut Point, head: &Point) {
+    if tail.chebyshev(*head) > 1 {
+        *tail += (*head - *tail).signum();
+    }
+}
+
+impl FromStr for Command {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = s.split_whitespace().collect_tuple().unwrap();
+        let distance = distance.parse().unwrap();
+        let direction = direction
+            .parse()
+            .unwrap_or_else(|_| panic!("Unknown direction: {}", direction));
+        Ok(Command {
+            direction,
+            distance,
+        })
+    }
+}
+
+fn debug_rope(rope: &[Point]) {
+    for (idx, position) in rope.iter().enumerate() {
+        if idx == 0 {
+            debug!("Head: {}", position);
+        } else {
+            debug!("{}: {}", idx, position);
+        }
+    }
+}
+
+/// Pushes the knots and the cells the tail has visited as a recording frame.
+fn record(rope: &[Point], visited: &HashSet<Point>) {
+    let knots = rope.iter().enumerate().rev().map(|(idx, knot)| {
+        let symbol = match idx {
+            0 => 'H',
+            _ if rope.len() == 2 => 'T',
+            _ => char::from_digit(idx as u32, 10).unwrap(),
+        };
+        (*knot, symbol)
+    });
+    Recorder::points(visited.iter().map(|pos| (*pos, '#')).chain(knots));
+}
+
+struct Solution {}
+impl Solver<'_, usize> for Solution {
+    fn solve_part_one(&self, lines: &[&str]) -> usize {
+        let mut commands = lines
+            .iter()
+            .map(|s| Command::from_str(s).unwrap())
+            .collect_vec();
+        debug!("{:?}", commands);
+
+        let mut head = Point::ORIGIN;
+        let mut tail = head;
+
+        let mut visited = HashSet::new();
+        visited.insert(tail);
+
+        for command in &mut commands {
+            loop {
+                move_by(&mut head, command);
+                step_tail_closer(&mut tail, &head);
+                visited.insert(tail);
+                record(&[head, tail], &visited);
+                if command.distance == 0 {
+                    break;
+                }
+            }
+        }
+
+        visited.len()
+    }
+
+    fn solve_part_two(&self, lines: &[&str]) -> usize {
+        let mut commands = lines
+            .iter()
+            .map(|s| Command::from_str(s).unwrap())
+            .collect_vec();
+        debug!("{:?}", commands);
+
+        let mut rope = [Point::ORIGIN; 10];
+
+        let mut visited = HashSet::new();
+        visited.insert(*rope.last().unwrap());
+
+        for command in &mut commands {
+            debug!("Command: {:?}", command);
+            loop {
+                move_by(&mut rope[0], command);
+                for (head_idx, tail_idx) in (0..rope.len()).tuple_windows() {
+                    let (head_side, tail_side) = rope.split_at_mut(tail_idx);
+                    let head = &mut head_side[head_idx];
+                    let tail = &mut tail_side[0];
+                    step_tail_closer(tail, head);
+                }
+                visited.insert(*rope.last().unwrap());
+                record(&rope, &visited);
+                debug_rope(&rope);
+                debug!("");
+                if command.distance == 0 {
+                    break;
+                }
+            }
+            debug!("");
+            debug!("");
+        }
+
+        visited.len()
+    }
+}
+
+fn main() {
+    let file = "9.txt";
+    let sample_2 = "9_2.txt";
+    let part_one_problems = [
+        aoc::Input::sample_file(file, 13),
+        aoc::Input::final_file(file),
+    ];
+
+    let part_two_problems = [
+        aoc::Input::sample_file(file, 1),
+        aoc::Input::sample_file(sample_2, 36),
+        aoc::Input::final_file(file),
+    ];
+
+    Solution {}.run(
+        aoc::Day::new(2022, 9),
+        &part_one_problems,
+        &part_two_problems,
+    );
+}
@@ -0,0 +1,466 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::{Bounds3, Connectivity, Grid, Grid3, Point3};
+
+type Pos = (usize, usize);
+
+const WORD: usize = u64::BITS as usize;
+
+/// Moves the bits of a row `dx` places towards higher `x`, dropping the ones
+/// that fall off either end.
+fn shift_row(src: &[u64], dx: isize) -> Vec<u64> {
+    let n = src.len();
+    let (words, bits) = (dx.unsigned_abs() / WORD, (dx.unsigned_abs() % WORD) as u32);
+    let mut out = vec![0; n];
+    for (i, word) in out.iter_mut().enumerate() {
+        if dx >= 0 {
+            if i >= words {
+                *word = src[i - words] << bits;
+                if bits > 0 && i > words {
+                    *word |= src[i - words - 1] >> (WORD as u32 - bits);
+                }
+            }
+        } else if i + words < n {
+            *word = src[i + words] >> bits;
+            if bits > 0 && i + words + 1 < n {
+                *word |= src[i + words + 1] << (WORD as u32 - bits);
+            }
+        }
+    }
+    out
+}
+
+/// A grid of booleans packed 64 to a word, one run of words per row, for
+/// occupancy that is updated or combined a whole row at a time.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        let words_per_row = width.div_ceil(WORD);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn from_points(
+        width: usize,
+        height: usize,
+        points: impl IntoIterator<Item = Pos>,
+    ) -> BitGrid {
+        let mut grid = BitGrid::new(width, height);
+        for pos in points {
+            grid.set(pos, true);
+        }
+        grid
+    }
+
+    fn index(&self, (x, y): Pos) -> Option<(usize, u64)> {
+        (x < self.width && y < self.height)
+            .then(|| (y * self.words_per_row + x / WORD, 1 << (x % WORD)))
+    }
+
+    /// Whether `pos` is set. Positions off the grid are unset.
+    pub fn get(&self, pos: Pos) -> bool {
+        self.index(pos)
+            .is_some_and(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Panics if `pos` is off the grid.
+    pub fn set(&mut self, pos: Pos, value: bool) {
+        let (word, bit) = self
+            .index(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside {}x{}", pos, self.width, self.height));
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+    }
+
+    /// The words of row `y`, with `x` at bit `x % 64` of word `x / 64`.
+    pub fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// Callers must leave the bits past `width` clear.
+    pub fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn row_count_ones(&self, y: usize) -> usize {
+        self.row(y).iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Positions that are set, row by row.
+    pub fn ones(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.words.iter().enumerate().flat_map(move |(idx, &word)| {
+            let (y, x0) = (idx / self.words_per_row, idx % self.words_per_row * WORD);
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    (x0 + bit, y)
+                })
+            })
+        })
+    }
+
+    /// Clears the bits past `width` in the last word of every row.
+    fn mask_padding(&mut self) {
+        let extra = self.words_per_row * WORD - self.width;
+        if extra > 0 {
+            let mask = u64::MAX >> extra;
+            for row in self.words.chunks_mut(self.words_per_row) {
+                row[row.len() - 1] &= mask;
+            }
+        }
+    }
+
+    /// Contents moved by `(dx, dy)`, with cells moved off the grid dropped.
+    pub fn shift(&self, (dx, dy): (isize, isize)) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        for y in 0..self.height {
+            let Some(src) = y.checked_add_signed(-dy).filter(|&src| src < self.height) else {
+                continue;
+            };
+            let row = shift_row(self.row(src), dx);
+            shifted.row_mut(y).copy_from_slice(&row);
+        }
+        shifted.mask_padding();
+        shifted
+    }
+
+    /// Cells with at least one set neighbour.
+    pub fn neighbor_mask(&self, connectivity: Connectivity) -> BitGrid {
+        let mut mask = BitGrid::new(self.width, self.height);
+        for &(dx, dy) in connectivity.offsets() {
+            mask |= &self.shift((-dx, -dy));
+        }
+        mask
+    }
+
+    fn zip_with(&mut self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "Combining grids of different sizes"
+        );
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, other: &BitGrid) {
+        self.zip_with(other, |a, b| a & b);
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        self.zip_with(other, |a, b| a | b);
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, other: &BitGrid) {
+        self.zip_with(other, |a, b| a ^ b);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out &= other;
+        out
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out |= other;
+        out
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        let mut out = self.clone();
+        out ^= other;
+        out
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut out = self.clone();
+        for word in out.words.iter_mut() {
+            *word = !*word;
+        }
+        out.mask_padding();
+        out
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> BitGrid {
+        BitGrid::from_points(
+            grid.width,
+            grid.height,
+            grid.enumerate()
+                .filter(|(_, set)| **set)
+                .map(|(pos, _)| pos),
+        )
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Grid<bool> {
+        Grid::from_fn(bits.width, bits.height, |pos| bits.get(pos))
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", if self.get((x, y)) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+
+/// A `BitGrid` per `z` layer covering `bounds`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitGrid3 {
+    pub bounds: Bounds3,
+    layers: Vec<BitGrid>,
+}
+
+impl BitGrid3 {
+    pub fn new(bounds: Bounds3) -> BitGrid3 {
+        let layer = BitGrid::new(bounds.width(), bounds.height());
+        BitGrid3 {
+            bounds,
+            layers: vec![layer; bounds.depth()],
+        }
+    }
+
+    pub fn from_points(bounds: Bounds3, points: impl IntoIterator<Item = Point3>) -> BitGrid3 {
+        let mut grid = BitGrid3::new(bounds);
+        for p in points {
+            grid.set(p, true);
+        }
+        grid
+    }
+
+    fn local(&self, p: Point3) -> Option<(usize, Pos)> {
+        self.bounds.contains(p).then(|| {
+            let Point3 { x, y, z } = p - self.bounds.min;
+            (z as usize, (x as usize, y as usize))
+        })
+    }
+
+    /// Whether `p` is set. Points outside the bounds are unset.
+    pub fn get(&self, p: Point3) -> bool {
+        self.local(p)
+            .is_some_and(|(z, pos)| self.layers[z].get(pos))
+    }
+
+    /// Panics if `p` is outside the bounds.
+    pub fn set(&mut self, p: Point3, value: bool) {
+        let (z, pos) = self
+            .local(p)
+            .unwrap_or_else(|| panic!("{} is outside {:?}", p, self.bounds));
+        self.layers[z].set(pos, value);
+    }
+
+    /// The `x`/`y` plane at `z`.
+    pub fn layer(&self, z: isize) -> &BitGrid {
+        &self.layers[(z - self.bounds.min.z) as usize]
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.layers.iter().map(BitGrid::count_ones).sum()
+    }
+
+    pub fn ones(&self) -> impl Iterator<Item = Point3> + '_ {
+        let min = self.bounds.min;
+        self.layers.iter().enumerate().flat_map(move |(z, layer)| {
+            layer
+                .ones()
+                .map(move |(x, y)| min + Point3::new(x as isize, y as isize, z as isize))
+        })
+    }
+
+    /// Contents moved by `offset`, with points moved out of bounds dropped.
+    pub fn shift(&self, offset: Point3) -> BitGrid3 {
+        let mut shifted = BitGrid3::new(self.bounds);
+        for (z, layer) in shifted.layers.iter_mut().enumerate() {
+            if let Some(src) = z
+                .checked_add_signed(-offset.z)
+                .filter(|&src| src < self.layers.len())
+            {
+                *layer = self.layers[src].shift((offset.x, offset.y));
+            }
+        }
+        shifted
+    }
+
+    /// Points with at least one set face neighbour.
+    pub fn neighbor_mask(&self) -> BitGrid3 {
+        let mut mask = BitGrid3::new(self.bounds);
+        for offset in Point3::default().neighbors6() {
+            mask |= &self.shift(-offset);
+        }
+        mask
+    }
+
+    fn zip_with(&mut self, other: &BitGrid3, f: impl Fn(&mut BitGrid, &BitGrid)) {
+        assert_eq!(
+            self.bounds, other.bounds,
+            "Combining grids of different bounds"
+        );
+        for (a, b) in self.layers.iter_mut().zip(&other.layers) {
+            f(a, b);
+        }
+    }
+}
+
+impl BitAndAssign<&BitGrid3> for BitGrid3 {
+    fn bitand_assign(&mut self, other: &BitGrid3) {
+        self.zip_with(other, |a, b| *a &= b);
+    }
+}
+
+impl BitOrAssign<&BitGrid3> for BitGrid3 {
+    fn bitor_assign(&mut self, other: &BitGrid3) {
+        self.zip_with(other, |a, b| *a |= b);
+    }
+}
+
+impl BitXorAssign<&BitGrid3> for BitGrid3 {
+    fn bitxor_assign(&mut self, other: &BitGrid3) {
+        self.zip_with(other, |a, b| *a ^= b);
+    }
+}
+
+impl Not for &BitGrid3 {
+    type Output = BitGrid3;
+
+    fn not(self) -> BitGrid3 {
+        BitGrid3 {
+            bounds: self.bounds,
+            layers: self.layers.iter().map(|layer| !layer).collect(),
+        }
+    }
+}
+
+impl From<&Grid3<bool>> for BitGrid3 {
+    fn from(grid: &Grid3<bool>) -> BitGrid3 {
+        BitGrid3::from_points(grid.bounds, grid.positions().filter(|&p| *grid.at(p)))
+    }
+}
+
+impl From<&BitGrid3> for Grid3<bool> {
+    fn from(bits: &BitGrid3) -> Grid3<bool> {
+        Grid3::from_points(bits.bounds, bits.ones(), true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_row() {
+        let row = [1 << 63 | 1, 1];
+        assert_eq!(shift_row(&row, 1), [2, 1 << 1 | 1]);
+        assert_eq!(shift_row(&row, -1), [1 << 62 | 1 << 63, 0]);
+        assert_eq!(shift_row(&row, 64), [0, 1 << 63 | 1]);
+        assert_eq!(shift_row(&row, -65), [0, 0]);
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let grid = Grid::from_lines(&["#....", "..#..", "....#"], &|c| c == '#');
+        let bits = BitGrid::from(&grid);
+        assert_eq!(bits.count_ones(), 3);
+        assert_eq!(bits.ones().collect::<Vec<_>>(), [(0, 0), (2, 1), (4, 2)]);
+        assert_eq!(Grid::from(&bits), grid);
+        assert_eq!(bits.to_string(), "\n#....\n..#..\n....#\n\n");
+
+        assert_eq!(bits.shift((1, 1)).to_string(), "\n.....\n.#...\n...#.\n\n");
+        assert_eq!(bits.shift((-1, 0)).count_ones(), 2);
+        assert_eq!((!&bits).count_ones(), 12);
+        assert_eq!(
+            (&bits & &bits.shift((2, 1))).ones().collect::<Vec<_>>(),
+            [(2, 1), (4, 2)]
+        );
+
+        let mask = bits.neighbor_mask(Connectivity::Four);
+        assert_eq!(mask.to_string(), "\n.##..\n##.##\n..##.\n\n");
+
+        // Cells with no neighbours at all
+        let bits = BitGrid::from_points(5, 3, [(0, 0), (1, 1), (4, 2)]);
+        let lonely = &bits & &!&bits.neighbor_mask(Connectivity::Eight);
+        assert_eq!(lonely.ones().collect::<Vec<_>>(), [(4, 2)]);
+    }
+
+    #[test]
+    fn test_wide_bit_grid() {
+        let mut bits = BitGrid::new(130, 2);
+        bits.set((63, 0), true);
+        bits.set((129, 1), true);
+        assert_eq!(bits.shift((1, 0)).ones().collect::<Vec<_>>(), [(64, 0)]);
+        assert_eq!(bits.shift((-66, -1)).ones().collect::<Vec<_>>(), [(63, 0)]);
+        assert_eq!((!&bits).count_ones(), 258);
+    }
+
+    #[test]
+    fn test_bit_grid3() {
+        let bounds = Bounds3::new(Point3::new(-1, -1, -1), Point3::new(1, 1, 1));
+        let bits = BitGrid3::from_points(bounds, [Point3::default()]);
+        let mask = bits.neighbor_mask();
+        assert_eq!(mask.count_ones(), 6);
+        assert!(mask.get(Point3::new(0, 0, -1)) && !mask.get(Point3::default()));
+        assert_eq!(
+            bits.shift(Point3::new(0, 0, 1)).ones().collect::<Vec<_>>(),
+            [Point3::new(0, 0, 1)]
+        );
+        assert_eq!(BitGrid3::from(&Grid3::from(&mask)), mask);
+    }
+}
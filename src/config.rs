@@ -0,0 +1,357 @@
+use log::LevelFilter;
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{Cli, Day, Part, RecordFormat, Target};
+
+pub const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Unknown output format {s}")),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Settings read from `aoc.toml`. Every field is optional in the file, and any
+/// flag passed on the command line takes precedence over it.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Calendar `fetch.sh` and `run.sh` use when no `--year` is given. A
+    /// running day replaces it with its own year or the `--year` flag.
+    pub year: u16,
+    /// Inputs, samples and answers are laid out as `<dir>/<year>/<file>`.
+    pub input_dir: PathBuf,
+    pub sample_dir: PathBuf,
+    pub answer_dir: PathBuf,
+    #[serde(deserialize_with = "from_str")]
+    pub log_level: LevelFilter,
+    #[serde(deserialize_with = "from_str")]
+    pub part: Part,
+    #[serde(deserialize_with = "from_str")]
+    pub target: Target,
+    pub parallel: bool,
+    /// Seconds a single (part, input) run may take before the runner gives up.
+    pub timeout: Option<u64>,
+    #[serde(deserialize_with = "from_str")]
+    pub format: OutputFormat,
+    /// Write each run's log to `<trace_dir>/<year>/<day>/part<part>_<input>.log`
+    pub trace_dir: Option<PathBuf>,
+    #[serde(deserialize_with = "from_str")]
+    pub trace_level: LevelFilter,
+    /// Module prefixes to trace, e.g. `2022_22`. Everything is traced if empty.
+    pub trace_modules: Vec<String>,
+    /// Trace files stop growing after this many bytes
+    pub trace_max_bytes: Option<u64>,
+    /// Write frames pushed to `aoc::Recorder` to
+    /// `<record_dir>/<year>/<day>/part<part>_<input>.<format>`
+    pub record_dir: Option<PathBuf>,
+    #[serde(deserialize_with = "from_str")]
+    pub record_format: RecordFormat,
+    /// Pixels per cell in gif and png recordings
+    pub record_scale: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: 2022,
+            input_dir: PathBuf::from("inputs"),
+            sample_dir: PathBuf::from("samples"),
+            answer_dir: PathBuf::from("answers"),
+            log_level: LevelFilter::Info,
+            part: Part::All,
+            target: Target::All,
+            parallel: true,
+            timeout: None,
+            format: OutputFormat::Text,
+            trace_dir: None,
+            trace_level: LevelFilter::Debug,
+            trace_modules: vec![],
+            trace_max_bytes: None,
+            record_dir: None,
+            record_format: RecordFormat::Cast,
+            record_scale: 4,
+        }
+    }
+}
+
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
+impl Config {
+    /// Reads a config file. Relative directories are resolved against the
+    /// directory containing the file.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        let mut config: Config = toml::from_str(&contents)
+            .map_err(|e| format!("Could not parse {}: {e}", path.display()))?;
+
+        if let Some(base) = path.parent() {
+            let resolve = |dir: &mut PathBuf| {
+                if dir.is_relative() {
+                    *dir = base.join(&*dir);
+                }
+            };
+            resolve(&mut config.input_dir);
+            resolve(&mut config.sample_dir);
+            resolve(&mut config.answer_dir);
+            if let Some(dir) = &mut config.trace_dir {
+                resolve(dir);
+            }
+            if let Some(dir) = &mut config.record_dir {
+                resolve(dir);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Loads the config named on the command line (or `aoc.toml` if it
+    /// exists) and applies the command line overrides on top of it, for a
+    /// run of `day`.
+    pub fn from_cli(args: &Cli, day: Day) -> Result<Config, String> {
+        let mut config = match &args.config {
+            Some(path) => Config::load(path)?,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                Config::load(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            None => Config::default(),
+        };
+
+        config.year = args.year.unwrap_or(day.year);
+        if let Some(dir) = &args.input_dir {
+            config.input_dir = dir.clone();
+        }
+        if let Some(dir) = &args.sample_dir {
+            config.sample_dir = dir.clone();
+        }
+        if let Some(dir) = &args.answer_dir {
+            config.answer_dir = dir.clone();
+        }
+        if let Some(log_level) = args.log_level {
+            config.log_level = log_level;
+        }
+        if let Some(part) = args.part {
+            config.part = part;
+        }
+        if let Some(target) = args.target {
+            config.target = target;
+        }
+        if args.parallel {
+            config.parallel = true;
+        } else if args.no_parallel {
+            config.parallel = false;
+        }
+        if let Some(timeout) = args.timeout {
+            config.timeout = Some(timeout);
+        }
+        if let Some(format) = args.format {
+            config.format = format;
+        }
+        if let Some(dir) = &args.trace_dir {
+            config.trace_dir = Some(dir.clone());
+        }
+        if let Some(level) = args.trace_level {
+            config.trace_level = level;
+        }
+        if !args.trace_modules.is_empty() {
+            config.trace_modules = args.trace_modules.clone();
+        }
+        if let Some(max_bytes) = args.trace_max_bytes {
+            config.trace_max_bytes = Some(max_bytes);
+        }
+        if let Some(dir) = &args.record_dir {
+            config.record_dir = Some(dir.clone());
+        }
+        if let Some(format) = args.record_format {
+            config.record_format = format;
+        }
+        if let Some(scale) = args.record_scale {
+            config.record_scale = scale;
+        }
+        Ok(config)
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
+
+    pub fn input_path(&self, day: Day, name: &str) -> PathBuf {
+        self.input_dir.join(day.year.to_string()).join(name)
+    }
+
+    pub fn sample_path(&self, day: Day, name: &str) -> PathBuf {
+        self.sample_dir.join(day.year.to_string()).join(name)
+    }
+
+    pub fn answer_path(&self, day: Day, name: &str) -> PathBuf {
+        self.answer_dir.join(day.year.to_string()).join(name)
+    }
+
+    pub fn trace_path(&self, day: Day, part: u8, input_label: &str) -> Option<PathBuf> {
+        self.trace_dir.as_ref().map(|dir| {
+            dir.join(day.year.to_string())
+                .join(day.day.to_string())
+                .join(format!("part{part}_{input_label}.log"))
+        })
+    }
+
+    pub fn record_path(&self, day: Day, part: u8, input_label: &str) -> Option<PathBuf> {
+        self.record_dir.as_ref().map(|dir| {
+            dir.join(day.year.to_string())
+                .join(day.day.to_string())
+                .join(format!(
+                    "part{part}_{input_label}{}",
+                    self.record_format.extension()
+                ))
+        })
+    }
+
+    /// Expected final answer for `part`, stored as line `part` of
+    /// `<answer_dir>/<year>/<input file name>`.
+    pub fn answer(&self, day: Day, input_name: &str, part: u8) -> Option<String> {
+        let contents = fs::read_to_string(self.answer_path(day, input_name)).ok()?;
+        contents
+            .lines()
+            .nth(part as usize - 1)
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_keeps_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            year = 2021
+            sample_dir = "examples"
+            log_level = "debug"
+            part = "2"
+            target = "samples"
+            timeout = 30
+            format = "json"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, 2021);
+        assert_eq!(config.input_dir, PathBuf::from("inputs"));
+        assert_eq!(config.sample_dir, PathBuf::from("examples"));
+        assert_eq!(config.log_level, LevelFilter::Debug);
+        assert_eq!(
+            config.sample_path(Day::new(2021, 3), "3.txt"),
+            PathBuf::from("examples/2021/3.txt")
+        );
+        assert!(config.part == Part::Two);
+        assert!(config.target == Target::Samples);
+        assert!(config.parallel);
+        assert_eq!(config.timeout(), Some(Duration::from_secs(30)));
+        assert_eq!(config.format, OutputFormat::Json);
+        assert_eq!(config.trace_path(Day::new(2021, 3), 1, "final"), None);
+    }
+
+    #[test]
+    fn test_trace_path() {
+        let config: Config = toml::from_str(
+            r#"
+            trace_dir = "traces"
+            trace_modules = ["2022_22"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.trace_path(Day::new(2022, 22), 2, "sample1"),
+            Some(PathBuf::from("traces/2022/22/part2_sample1.log"))
+        );
+        assert_eq!(config.trace_level, LevelFilter::Debug);
+    }
+
+    #[test]
+    fn test_record_path() {
+        let config: Config = toml::from_str(
+            r#"
+            record_dir = "frames"
+            record_format = "gif"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.record_path(Day::new(2022, 23), 2, "final"),
+            Some(PathBuf::from("frames/2022/23/part2_final.gif"))
+        );
+        assert_eq!(config.record_scale, 4);
+    }
+
+    #[test]
+    fn test_unknown_keys_are_rejected() {
+        assert!(toml::from_str::<Config>("inputs = \"foo\"").is_err());
+    }
+
+    #[test]
+    fn test_parallel_flags_override_config() {
+        use clap::Parser;
+        let parallel = |args: &[&str]| {
+            let cli = Cli::try_parse_from(std::iter::once("aoc").chain(args.iter().copied()));
+            Config::from_cli(&cli.unwrap(), Day::new(2022, 1))
+                .unwrap()
+                .parallel
+        };
+        assert!(!parallel(&["--no-parallel"]));
+        assert!(parallel(&["--no-parallel", "--parallel"]));
+        assert!(!parallel(&["--parallel", "--no-parallel"]));
+    }
+
+    #[test]
+    fn test_year_flag_overrides_day() {
+        use clap::Parser;
+        let year = |args: &[&str]| {
+            let cli = Cli::try_parse_from(std::iter::once("aoc").chain(args.iter().copied()));
+            Config::from_cli(&cli.unwrap(), Day::new(2021, 3))
+                .unwrap()
+                .year
+        };
+        assert_eq!(year(&[]), 2021);
+        assert_eq!(year(&["--year", "2015"]), 2015);
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Direction, Grid, Point3};
+
+type Pos = (usize, usize);
+
+/// Placement of one face of the folded cube. `normal` points out of the cube,
+/// `right` and `down` are where the face's `+x` and `+y` point after folding.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Face {
+    origin: Pos,
+    normal: Point3,
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    /// Direction from the centre of the face towards `edge`.
+    fn edge_vector(&self, edge: Direction) -> Point3 {
+        match edge {
+            Direction::Up => -self.down,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+            Direction::Right => self.right,
+            _ => panic!("Cube faces have no {} edge", edge),
+        }
+    }
+
+    /// Corners at either end of `edge`, in the order the edge's cells are
+    /// numbered on this face.
+    fn edge_corners(&self, edge: Direction) -> (Point3, Point3) {
+        let corner = |right: bool, down: bool| {
+            let r = if right { self.right } else { -self.right };
+            let d = if down { self.down } else { -self.down };
+            self.normal + r + d
+        };
+        match edge {
+            Direction::Up => (corner(false, false), corner(true, false)),
+            Direction::Down => (corner(false, true), corner(true, true)),
+            Direction::Left => (corner(false, false), corner(false, true)),
+            Direction::Right => (corner(true, false), corner(true, true)),
+            _ => panic!("Cube faces have no {} edge", edge),
+        }
+    }
+
+    /// The neighbouring face across `edge` in the net, folded onto the cube.
+    fn fold(&self, edge: Direction, origin: Pos) -> Face {
+        let (normal, right, down) = match edge {
+            Direction::Up => (-self.down, self.right, self.normal),
+            Direction::Down => (self.down, self.right, -self.normal),
+            Direction::Left => (-self.right, self.normal, self.down),
+            Direction::Right => (self.right, -self.normal, self.down),
+            _ => panic!("Cube faces have no {} edge", edge),
+        };
+        Face {
+            origin,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+/// A flat net of six square faces, folded into a cube so that walking off
+/// the edge of one face continues on the face it touches on the cube.
+#[derive(Clone, Debug)]
+pub struct CubeNet {
+    size: usize,
+    faces: Vec<Face>,
+    face_at: HashMap<Pos, usize>,
+}
+
+impl CubeNet {
+    /// Detects the faces of the net drawn in `grid`, where cells matching
+    /// `empty` are outside the net.
+    pub fn from_grid<T: Default + Clone>(
+        grid: &Grid<T>,
+        empty: impl Fn(&T) -> bool,
+    ) -> Result<CubeNet, String> {
+        let cells = grid.state.iter().filter(|t| !empty(t)).count();
+        let size = ((cells / 6) as f64).sqrt() as usize;
+        if size == 0 || 6 * size * size != cells {
+            return Err(format!("{} cells cannot make six square faces", cells));
+        }
+        let origins = (0..grid.height / size)
+            .flat_map(|y| (0..grid.width / size).map(move |x| (x, y)))
+            .filter(|&(x, y)| !empty(grid.at((x * size, y * size))))
+            .collect::<Vec<_>>();
+        CubeNet::from_faces(size, &origins)
+    }
+
+    /// Folds a net of faces with side `size`, given the face positions in
+    /// units of whole faces, e.g. `(2, 0)` for the face whose top left cell
+    /// is at `(2 * size, 0)`.
+    pub fn from_faces(size: usize, origins: &[Pos]) -> Result<CubeNet, String> {
+        if origins.len() != 6 {
+            return Err(format!("A cube has 6 faces, the net has {}", origins.len()));
+        }
+        let face_at: HashMap<Pos, usize> = origins
+            .iter()
+            .enumerate()
+            .map(|(idx, &origin)| (origin, idx))
+            .collect();
+
+        let mut placed: Vec<Option<Face>> = vec![None; 6];
+        placed[0] = Some(Face {
+            origin: origins[0],
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        });
+        let mut queue = VecDeque::from([0]);
+        while let Some(idx) = queue.pop_front() {
+            let face = placed[idx].unwrap();
+            for edge in Direction::CARDINAL {
+                let offset = edge.offset();
+                let Some(origin) = face
+                    .origin
+                    .0
+                    .checked_add_signed(offset.x)
+                    .zip(face.origin.1.checked_add_signed(offset.y))
+                else {
+                    continue;
+                };
+                if let Some(&next) = face_at.get(&origin) {
+                    if placed[next].is_none() {
+                        placed[next] = Some(face.fold(edge, origin));
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        let faces = placed
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or("The faces of the net are not connected")?;
+        for (idx, face) in faces.iter().enumerate() {
+            if let Some(other) = faces[..idx].iter().find(|o| o.normal == face.normal) {
+                return Err(format!(
+                    "Faces at {:?} and {:?} overlap when folded",
+                    other.origin, face.origin
+                ));
+            }
+        }
+
+        Ok(CubeNet {
+            size,
+            faces,
+            face_at,
+        })
+    }
+
+    /// Side length of a face.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Index of the face containing `pos`, if it is on the net.
+    pub fn face_of(&self, pos: Pos) -> Option<usize> {
+        self.face_at
+            .get(&(pos.0 / self.size, pos.1 / self.size))
+            .copied()
+    }
+
+    /// Moves one cell from `pos` in `direction`, returning the new position
+    /// and the direction of travel, which changes when crossing onto a face
+    /// that is not next to this one in the net.
+    pub fn step(&self, pos: Pos, direction: Direction) -> (Pos, Direction) {
+        let offset = direction.offset();
+        let next = pos
+            .0
+            .checked_add_signed(offset.x)
+            .zip(pos.1.checked_add_signed(offset.y));
+        if let Some(next) = next.filter(|&next| self.face_of(next).is_some()) {
+            return (next, direction);
+        }
+
+        let s = self.size;
+        let from = self.faces[self.face_of(pos).expect("Position is not on the cube")];
+        let along = match direction {
+            Direction::Up | Direction::Down => pos.0 % s,
+            _ => pos.1 % s,
+        };
+
+        let normal = from.edge_vector(direction);
+        let to = *self.faces.iter().find(|f| f.normal == normal).unwrap();
+        let edge = Direction::CARDINAL
+            .iter()
+            .copied()
+            .find(|&edge| to.edge_vector(edge) == from.normal)
+            .unwrap();
+
+        let along = if from.edge_corners(direction).0 == to.edge_corners(edge).0 {
+            along
+        } else {
+            s - 1 - along
+        };
+        let (x, y) = match edge {
+            Direction::Up => (along, 0),
+            Direction::Down => (along, s - 1),
+            Direction::Left => (0, along),
+            _ => (s - 1, along),
+        };
+        (
+            (to.origin.0 * s + x, to.origin.1 * s + y),
+            edge.turn_around(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The 11 nets of a cube.
+    const NETS: [&[&str]; 11] = [
+        &["#...", "####", "#..."],
+        &["#...", "####", ".#.."],
+        &["#...", "####", "..#."],
+        &["#...", "####", "...#"],
+        &[".#..", "####", ".#.."],
+        &[".#..", "####", "..#."],
+        &["##..", ".###", ".#.."],
+        &["##..", ".###", "..#."],
+        &["##..", ".###", "...#"],
+        &["##..", ".##.", "..##"],
+        &["###..", "..###"],
+    ];
+
+    fn fold(net: &[&str], size: usize) -> CubeNet {
+        let lines = net
+            .iter()
+            .flat_map(|row| {
+                let line: String = row
+                    .chars()
+                    .flat_map(|c| std::iter::repeat_n(c, size))
+                    .collect();
+                std::iter::repeat_n(line, size)
+            })
+            .collect::<Vec<_>>();
+        let lines = lines.iter().map(String::as_str).collect::<Vec<_>>();
+        let grid = Grid::from_lines(&lines, &|c| c);
+        CubeNet::from_grid(&grid, |c| *c == '.').unwrap()
+    }
+
+    #[test]
+    fn test_every_net_round_trips() {
+        let size = 3;
+        for net in NETS {
+            let cube = fold(net, size);
+            for face in &cube.faces {
+                let (fx, fy) = (face.origin.0 * size, face.origin.1 * size);
+                for i in 0..size {
+                    for (pos, direction) in [
+                        ((fx + i, fy), Direction::Up),
+                        ((fx + i, fy + size - 1), Direction::Down),
+                        ((fx, fy + i), Direction::Left),
+                        ((fx + size - 1, fy + i), Direction::Right),
+                    ] {
+                        let (next, heading) = cube.step(pos, direction);
+                        assert!(cube.face_of(next).is_some(), "{:?} {:?}", net, pos);
+                        let (back, returned) = cube.step(next, heading.turn_around());
+                        assert_eq!(
+                            (back, returned),
+                            (pos, direction.turn_around()),
+                            "{:?} from {:?} going {}",
+                            net,
+                            pos,
+                            direction
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_nets() {
+        assert!(CubeNet::from_faces(1, &[(0, 0), (1, 0), (2, 0), (3, 0), (0, 1), (1, 1)]).is_err());
+        assert!(CubeNet::from_faces(1, &[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (0, 1)]).is_err());
+        assert!(CubeNet::from_faces(1, &[(0, 0), (1, 0), (2, 0), (3, 0), (0, 2), (0, 3)]).is_err());
+        assert!(CubeNet::from_faces(1, &[(0, 0)]).is_err());
+    }
+}
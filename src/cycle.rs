@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating: the state after step
+/// `start + period` is the state after step `start`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Floyd's tortoise and hare, following `step` from `initial`.
+    pub fn floyd<S: PartialEq>(initial: S, step: impl Fn(&S) -> S) -> Cycle {
+        let mut tortoise = step(&initial);
+        let mut hare = step(&tortoise);
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&step(&hare));
+        }
+
+        let mut start = 0;
+        tortoise = initial;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            start += 1;
+        }
+
+        let mut period = 1;
+        hare = step(&tortoise);
+        while tortoise != hare {
+            hare = step(&hare);
+            period += 1;
+        }
+        Cycle { start, period }
+    }
+
+    /// Brent's algorithm, which calls `step` fewer times than `floyd`.
+    pub fn brent<S: PartialEq + Clone>(initial: S, step: impl Fn(&S) -> S) -> Cycle {
+        let (mut power, mut period) = (1, 1);
+        let mut tortoise = initial.clone();
+        let mut hare = step(&initial);
+        while tortoise != hare {
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            hare = step(&hare);
+            period += 1;
+        }
+
+        tortoise = initial.clone();
+        hare = initial;
+        for _ in 0..period {
+            hare = step(&hare);
+        }
+        let mut start = 0;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            start += 1;
+        }
+        Cycle { start, period }
+    }
+
+    /// The step in the first repetition whose state matches step `n`.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// Extrapolates a quantity that changes by the same amount every period,
+    /// like the height of a tower, to step `n`. `value` is only asked for
+    /// steps up to `start + period`.
+    pub fn extrapolate(&self, n: usize, value: impl Fn(usize) -> i64) -> i64 {
+        let index = self.index(n);
+        if index == n {
+            return value(n);
+        }
+        let per_period = value(self.start + self.period) - value(self.start);
+        value(index) + ((n - index) / self.period) as i64 * per_period
+    }
+}
+
+/// Finds a cycle by remembering every key seen, for simulations that are
+/// easier to run step by step than to express as a pure `step` function.
+/// The key must capture everything that determines the following steps.
+#[derive(Clone, Debug)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+    values: Vec<i64>,
+    cycle: Option<Cycle>,
+}
+
+impl<K: Hash + Eq> Default for CycleDetector<K> {
+    fn default() -> Self {
+        CycleDetector {
+            seen: HashMap::new(),
+            values: vec![],
+            cycle: None,
+        }
+    }
+}
+
+impl<K: Hash + Eq> CycleDetector<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the key and measured value after the next step, returning the
+    /// cycle once a key repeats. Nothing more is recorded after that.
+    pub fn record(&mut self, key: K, value: i64) -> Option<Cycle> {
+        if self.cycle.is_none() {
+            let step = self.values.len();
+            self.values.push(value);
+            if let Some(&start) = self.seen.get(&key) {
+                self.cycle = Some(Cycle {
+                    start,
+                    period: step - start,
+                });
+            } else {
+                self.seen.insert(key, step);
+            }
+        }
+        self.cycle
+    }
+
+    /// The value after step `n`, if it was recorded or can be extrapolated.
+    pub fn value_at(&self, n: usize) -> Option<i64> {
+        match (self.values.get(n), self.cycle) {
+            (Some(&value), _) => Some(value),
+            (None, Some(cycle)) => Some(cycle.extrapolate(n, |step| self.values[step])),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_floyd_and_brent() {
+        let expected = Cycle {
+            start: 2,
+            period: 6,
+        };
+        assert_eq!(Cycle::floyd(3, step), expected);
+        assert_eq!(Cycle::brent(3, step), expected);
+        assert_eq!(
+            Cycle::brent(0, |x| (x + 1) % 5),
+            Cycle {
+                start: 0,
+                period: 5
+            }
+        );
+        assert_eq!(expected.index(1), 1);
+        assert_eq!(expected.index(8), 2);
+        assert_eq!(expected.index(15), 3);
+    }
+
+    #[test]
+    fn test_detector_extrapolates() {
+        let mut detector = CycleDetector::new();
+        let (mut x, mut total) = (3, 0);
+        let cycle = loop {
+            x = step(&x);
+            total += x as i64;
+            if let Some(cycle) = detector.record(x, total) {
+                break cycle;
+            }
+        };
+        // Steps are counted from the first recorded state, 10
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 1,
+                period: 6
+            }
+        );
+
+        let mut brute = (3, 0);
+        for n in 0..100 {
+            brute.0 = step(&brute.0);
+            brute.1 += brute.0 as i64;
+            assert_eq!(detector.value_at(n), Some(brute.1), "step {}", n);
+        }
+    }
+}
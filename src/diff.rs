@@ -0,0 +1,218 @@
+use std::fmt;
+use std::fmt::Display;
+use std::io::{self, IsTerminal};
+
+use itertools::Itertools;
+
+use crate::render::RESET;
+use crate::{Grid, Point, SparseGrid, Style};
+
+type Pos = (usize, usize);
+
+/// A cell that differs between two states of a grid. `None` is a cell off a
+/// dense grid or an empty cell of a sparse one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Change<'a, P, T> {
+    pub pos: P,
+    pub before: Option<&'a T>,
+    pub after: Option<&'a T>,
+}
+
+impl<P: Into<Point> + Copy, T: Display> Display for Change<'_, P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show = |value: Option<&T>| value.map_or(".".to_string(), |v| v.to_string());
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.pos.into(),
+            show(self.before),
+            show(self.after)
+        )
+    }
+}
+
+/// Symbols for the cell in both states, and whether it changed.
+type Cell = (char, char, bool);
+
+fn cell<T: PartialEq>(
+    before: Option<&T>,
+    after: Option<&T>,
+    empty: char,
+    symbol: &impl Fn(&T) -> char,
+) -> Cell {
+    let show = |value: Option<&T>| value.map_or(empty, symbol);
+    (show(before), show(after), before != after)
+}
+
+/// Two states of a grid next to each other, with the changed cells
+/// highlighted red before and green after. Colours follow the same rules as
+/// `Render`.
+pub struct SideBySide {
+    cells: Grid<Cell>,
+    color: bool,
+}
+
+impl SideBySide {
+    fn new(cells: Grid<Cell>) -> Self {
+        SideBySide {
+            cells,
+            color: io::stdout().is_terminal(),
+        }
+    }
+
+    /// Forces escape codes on or off.
+    pub fn color(self, color: bool) -> Self {
+        SideBySide { color, ..self }
+    }
+
+    fn write_half(
+        &self,
+        f: &mut fmt::Formatter,
+        y: usize,
+        side: impl Fn(&Cell) -> char,
+        highlight: Style,
+    ) -> fmt::Result {
+        for cell in self.cells.row(y) {
+            if self.color && cell.2 {
+                write!(f, "{}{}{RESET}", highlight.escape(), side(cell))?;
+            } else {
+                write!(f, "{}", side(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for SideBySide {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        for y in 0..self.cells.height {
+            self.write_half(f, y, |cell| cell.0, Style::PLAIN.bg([160, 0, 0]))?;
+            write!(f, " | ")?;
+            self.write_half(f, y, |cell| cell.1, Style::PLAIN.bg([0, 130, 0]))?;
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl<T: Default + Clone + PartialEq> Grid<T> {
+    /// Cells that differ in `other`, row by row. Grids of different sizes
+    /// are compared over the larger of the two.
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> Vec<Change<'a, Pos, T>> {
+        let width = self.width.max(other.width);
+        let height = self.height.max(other.height);
+        (0..height)
+            .cartesian_product(0..width)
+            .filter_map(|(y, x)| {
+                let (before, after) = (self.get((x, y)), other.get((x, y)));
+                (before != after).then_some(Change {
+                    pos: (x, y),
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+
+    /// This grid next to `other`, with cells drawn as `symbol` and blanks
+    /// where one grid is smaller than the other.
+    pub fn side_by_side(&self, other: &Grid<T>, symbol: impl Fn(&T) -> char) -> SideBySide {
+        let width = self.width.max(other.width);
+        let height = self.height.max(other.height);
+        SideBySide::new(Grid::from_fn(width, height, |pos| {
+            cell(self.get(pos), other.get(pos), ' ', &symbol)
+        }))
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Cells that differ in `other`, row by row.
+    pub fn diff<'a>(&'a self, other: &'a SparseGrid<T>) -> Vec<Change<'a, Point, T>> {
+        self.positions()
+            .chain(other.positions().filter(|&pos| !self.contains(pos)))
+            .filter_map(|pos| {
+                let (before, after) = (self.get(pos), other.get(pos));
+                (before != after).then_some(Change { pos, before, after })
+            })
+            .sorted_by_key(|change| (change.pos.y, change.pos.x))
+            .collect()
+    }
+
+    /// This grid next to `other` over the bounds of both, with cells drawn
+    /// as `symbol` and empty cells as `.`.
+    pub fn side_by_side(&self, other: &SparseGrid<T>, symbol: impl Fn(&T) -> char) -> SideBySide {
+        let corners = self.bounds().into_iter().chain(other.bounds());
+        let cells = match corners.reduce(|(lo, hi), (a, b)| {
+            (
+                Point::new(lo.x.min(a.x), lo.y.min(a.y)),
+                Point::new(hi.x.max(b.x), hi.y.max(b.y)),
+            )
+        }) {
+            Some((lo, hi)) => {
+                let width = (hi.x - lo.x + 1) as usize;
+                let height = (hi.y - lo.y + 1) as usize;
+                Grid::from_fn(width, height, |pos| {
+                    let pos = lo + Point::from(pos);
+                    cell(self.get(pos), other.get(pos), '.', &symbol)
+                })
+            }
+            None => Grid::new(vec![], 0, 0),
+        };
+        SideBySide::new(cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_diff() {
+        let before = Grid::from_lines(&["ab", "cd"], &|c| c);
+        let after = Grid::from_lines(&["ax", "cd", "ef"], &|c| c);
+        let changes = before.diff(&after);
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.to_string())
+                .collect_vec(),
+            ["(1, 0): b -> x", "(0, 2): . -> e", "(1, 2): . -> f"]
+        );
+        assert!(before.diff(&before).is_empty());
+
+        let side_by_side = before.side_by_side(&after, |c| *c);
+        assert_eq!(
+            side_by_side.color(false).to_string(),
+            "\nab | ax\ncd | cd\n   | ef\n\n"
+        );
+        let highlighted = Grid::from_lines(&["a"], &|c| c)
+            .side_by_side(&Grid::from_lines(&["b"], &|c| c), |c| *c)
+            .color(true);
+        assert_eq!(
+            highlighted.to_string(),
+            "\n\x1b[48;2;160;0;0ma\x1b[0m | \x1b[48;2;0;130;0mb\x1b[0m\n\n"
+        );
+    }
+
+    #[test]
+    fn test_sparse_diff() {
+        let sparse = |cells: &[(isize, isize)]| -> SparseGrid<()> {
+            cells.iter().map(|&pos| (pos, ())).collect()
+        };
+        let before = sparse(&[(0, 0), (1, 0)]);
+        let after = sparse(&[(0, 0), (1, -1)]);
+        let changes = before.diff(&after);
+        assert_eq!(
+            changes.iter().map(|change| change.pos).collect_vec(),
+            [Point::new(1, -1), Point::new(1, 0)]
+        );
+        assert_eq!((changes[0].before, changes[0].after), (None, Some(&())));
+
+        let side_by_side = before.side_by_side(&after, |_| '#');
+        assert_eq!(
+            side_by_side.color(false).to_string(),
+            "\n.. | .#\n## | #.\n\n"
+        );
+    }
+}
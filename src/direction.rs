@@ -0,0 +1,171 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Point;
+
+/// One of the 8 compass directions on a grid where `y` grows downwards.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All 8 directions, clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    fn from_index(idx: usize) -> Direction {
+        Direction::ALL[idx % 8]
+    }
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(&self) -> Direction {
+        Direction::from_index(self.index() + 2)
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        Direction::from_index(self.index() + 6)
+    }
+
+    pub fn turn_around(&self) -> Direction {
+        Direction::from_index(self.index() + 4)
+    }
+
+    /// Turns 45 degrees, e.g. from `Up` to `UpRight` when clockwise.
+    pub fn turn_eighth(&self, clockwise: bool) -> Direction {
+        Direction::from_index(self.index() + if clockwise { 1 } else { 7 })
+    }
+
+    pub fn rotate(&self, clockwise: bool) -> Direction {
+        if clockwise {
+            self.turn_right()
+        } else {
+            self.turn_left()
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Parses the single character spellings used by puzzles: `U/D/L/R`, `^v<>`
+/// and `N/S/E/W`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            _ => Err(format!("Unknown direction {c}")),
+        }
+    }
+}
+
+/// Accepts any single character form plus the diagonals `NE`, `NW`, `SE`
+/// and `SW`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction::UpRight),
+            "SE" => Ok(Direction::DownRight),
+            "SW" => Ok(Direction::DownLeft),
+            "NW" => Ok(Direction::UpLeft),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Direction::try_from(c),
+                    _ => Err(format!("Unknown direction {s}")),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_around(), Direction::Right);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::Up.turn_eighth(false), Direction::UpLeft);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_around().turn_around(), direction);
+            assert_eq!(direction.turn_around().offset(), -direction.offset());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for (s, direction) in [
+            ("U", Direction::Up),
+            ("v", Direction::Down),
+            ("W", Direction::Left),
+            (">", Direction::Right),
+            ("NE", Direction::UpRight),
+            ("SW", Direction::DownLeft),
+        ] {
+            assert_eq!(s.parse::<Direction>(), Ok(direction));
+        }
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UU".parse::<Direction>().is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Neg, Sub};
+
+/// Signed 3D position or offset.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: Point3) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Point3> {
+        let point = *self;
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .iter()
+        .map(move |&(dx, dy, dz)| point + Point3::new(dx, dy, dz))
+    }
+
+    /// The 26 points sharing a face, edge or corner with this one.
+    pub fn neighbors26(&self) -> impl Iterator<Item = Point3> {
+        let point = *self;
+        (-1..=1)
+            .cartesian_product(-1..=1)
+            .cartesian_product(-1..=1)
+            .filter(|&((dx, dy), dz)| dx != 0 || dy != 0 || dz != 0)
+            .map(move |((dx, dy), dz)| point + Point3::new(dx, dy, dz))
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Inclusive axis-aligned box between `min` and `max`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Bounds3 {
+    pub fn new(min: Point3, max: Point3) -> Bounds3 {
+        Bounds3 { min, max }
+    }
+
+    /// Smallest box containing all `points`, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point3>) -> Option<Bounds3> {
+        points.into_iter().fold(None, |bounds, p| {
+            Some(match bounds {
+                Some(Bounds3 { min, max }) => Bounds3::new(
+                    Point3::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                    Point3::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+                ),
+                None => Bounds3::new(p, p),
+            })
+        })
+    }
+
+    /// Box grown by `margin` on every side.
+    pub fn grow(&self, margin: isize) -> Bounds3 {
+        let margin = Point3::new(margin, margin, margin);
+        Bounds3::new(self.min - margin, self.max + margin)
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1).max(0) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1).max(0) as usize
+    }
+
+    pub fn depth(&self) -> usize {
+        (self.max.z - self.min.z + 1).max(0) as usize
+    }
+
+    pub fn volume(&self) -> usize {
+        self.width() * self.height() * self.depth()
+    }
+
+    /// All points in the box, in `z`, `y`, `x` order.
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let Bounds3 { min, max } = *self;
+        (min.z..=max.z)
+            .cartesian_product(min.y..=max.y)
+            .cartesian_product(min.x..=max.x)
+            .map(|((z, y), x)| Point3::new(x, y, z))
+    }
+}
+
+/// Dense voxel grid covering `bounds`, indexed by signed `Point3`.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Grid3<T> {
+    pub state: Vec<T>,
+    pub bounds: Bounds3,
+}
+
+impl<T: Default + Clone> Grid3<T> {
+    pub fn new_empty(bounds: Bounds3) -> Grid3<T> {
+        Grid3 {
+            state: vec![T::default(); bounds.volume()],
+            bounds,
+        }
+    }
+
+    /// Grid covering `bounds` with `value` at each of `points` and
+    /// `T::default()` elsewhere. Points outside `bounds` are ignored.
+    pub fn from_points(
+        bounds: Bounds3,
+        points: impl IntoIterator<Item = Point3>,
+        value: T,
+    ) -> Grid3<T> {
+        let mut grid = Grid3::new_empty(bounds);
+        for point in points {
+            if let Some(cell) = grid.get_mut(point) {
+                *cell = value.clone();
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.bounds.depth()
+    }
+
+    fn index(&self, p: Point3) -> Option<usize> {
+        if !self.bounds.contains(p) {
+            return None;
+        }
+        let Point3 { x, y, z } = p - self.bounds.min;
+        Some((z as usize * self.height() + y as usize) * self.width() + x as usize)
+    }
+
+    pub fn at(&self, p: Point3) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{} is outside {:?}", p, self.bounds))
+    }
+
+    pub fn mut_at(&mut self, p: Point3) -> &mut T {
+        let bounds = self.bounds;
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} is outside {:?}", p, bounds))
+    }
+
+    pub fn get(&self, p: Point3) -> Option<&T> {
+        self.index(p).map(|idx| &self.state[idx])
+    }
+
+    pub fn get_mut(&mut self, p: Point3) -> Option<&mut T> {
+        self.index(p).map(move |idx| &mut self.state[idx])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point3> {
+        self.bounds.points()
+    }
+
+    /// In-bounds face neighbours of `p`.
+    pub fn neighbors6(&self, p: Point3) -> impl Iterator<Item = (Point3, &T)> {
+        p.neighbors6()
+            .filter_map(move |n| self.get(n).map(|value| (n, value)))
+    }
+
+    /// In-bounds face, edge and corner neighbours of `p`.
+    pub fn neighbors26(&self, p: Point3) -> impl Iterator<Item = (Point3, &T)> {
+        p.neighbors26()
+            .filter_map(move |n| self.get(n).map(|value| (n, value)))
+    }
+
+    /// Cells reachable from `start` through face neighbours matching
+    /// `passable`, including `start` itself if it matches.
+    pub fn flood_fill(&self, start: Point3, passable: impl Fn(&T) -> bool) -> HashSet<Point3> {
+        let mut visited = HashSet::new();
+        if !self.get(start).is_some_and(&passable) {
+            return visited;
+        }
+        let mut queue = VecDeque::new();
+        visited.insert(start);
+        queue.push_back(start);
+        while let Some(p) = queue.pop_front() {
+            for (n, value) in self.neighbors6(p) {
+                if passable(value) && visited.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Face-connected groups of cells matching `member`.
+    pub fn components(&self, member: impl Fn(&T) -> bool) -> Vec<HashSet<Point3>> {
+        let mut seen = HashSet::new();
+        let mut components = Vec::new();
+        for p in self.positions() {
+            if seen.contains(&p) || !member(self.at(p)) {
+                continue;
+            }
+            let component = self.flood_fill(p, &member);
+            seen.extend(component.iter().copied());
+            components.push(component);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighborhoods() {
+        let origin = Point3::default();
+        assert_eq!(origin.neighbors6().count(), 6);
+        assert!(origin.neighbors6().all(|n| n.manhattan(origin) == 1));
+        assert_eq!(origin.neighbors26().unique().count(), 26);
+
+        let grid: Grid3<u8> = Grid3::new_empty(Bounds3::new(origin, Point3::new(2, 2, 2)));
+        assert_eq!(grid.neighbors6(origin).count(), 3);
+        assert_eq!(grid.neighbors26(origin).count(), 7);
+        assert_eq!(grid.neighbors26(Point3::new(1, 1, 1)).count(), 26);
+    }
+
+    #[test]
+    fn test_flood_fill_stops_at_walls() {
+        // Hollow 3x3x3 shell around (0, 0, 0), inside a box with one cell of air around it
+        let shell = Bounds3::new(Point3::new(-1, -1, -1), Point3::new(1, 1, 1))
+            .points()
+            .filter(|&p| p != Point3::default())
+            .collect_vec();
+        let bounds = Bounds3::from_points(shell.iter().copied()).unwrap().grow(1);
+        let grid = Grid3::from_points(bounds, shell, true);
+
+        let outside = grid.flood_fill(bounds.min, |wall| !wall);
+        assert_eq!(outside.len(), 5 * 5 * 5 - 27);
+        assert!(!outside.contains(&Point3::default()));
+
+        let mut components = grid.components(|wall| !wall);
+        components.sort_by_key(|c| c.len());
+        assert_eq!(
+            components.iter().map(|c| c.len()).collect_vec(),
+            vec![1, 98]
+        );
+        assert_eq!(grid.components(|wall| *wall).len(), 1);
+    }
+}
@@ -0,0 +1,193 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Grid;
+
+pub type Rgb = [u8; 3];
+
+/// An RGB bitmap that can be encoded as PPM or PNG without extra crates.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each cell of `grid` as a `scale` by `scale` square of `color`.
+    pub fn from_grid<T: Default + Clone>(
+        grid: &Grid<T>,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> Image {
+        let (width, height) = (grid.width * scale, grid.height * scale);
+        let colors: Vec<Rgb> = grid.state.iter().map(color).collect();
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| colors[(y / scale) * grid.width + x / scale])
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Binary PPM (`P6`).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    /// 8-bit RGB PNG, with the pixel data stored uncompressed.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0); // No filter
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]); // Bit depth, RGB, deflate, no filter, no interlace
+
+        let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        write_chunk(&mut out, b"IHDR", &header);
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes the image as PNG, or as PPM if `path` ends in `.ppm`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            _ => self.to_png(),
+        };
+        fs::write(path, bytes)
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Saves the grid as an image, see `Image::from_grid` and `Image::save`.
+    pub fn save_image(
+        &self,
+        path: &Path,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        Image::from_grid(self, scale, color).save(path)
+    }
+}
+
+/// Blends from `from` to `to` as `t` goes from 0 to 1, e.g. to shade heights.
+pub fn gradient(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    let mut out = [0; 3];
+    for i in 0..3 {
+        out[i] = (from[i] as f64 + (to[i] as f64 - from[i] as f64) * t).round() as u8;
+    }
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// Zlib stream made of stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_encode() {
+        let grid = Grid::from_lines(&["#.", ".#"], &|c| c == '#');
+        let image = Image::from_grid(&grid, 2, |&on| if on { [255, 0, 0] } else { [0; 3] });
+        assert_eq!((image.width, image.height), (4, 4));
+        assert_eq!(
+            image.pixels[..4],
+            [[255, 0, 0], [255, 0, 0], [0; 3], [0; 3]]
+        );
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+        // 4 rows of a filter byte and 12 colour bytes in a single stored block
+        let idat = 8 + 25;
+        assert_eq!(&png[idat + 4..idat + 8], b"IDAT");
+        assert_eq!(
+            u32::from_be_bytes([png[idat], png[idat + 1], png[idat + 2], png[idat + 3]]),
+            2 + 5 + 4 * 13 + 4
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        assert_eq!(gradient([0, 0, 0], [255, 100, 10], 0.5), [128, 50, 5]);
+        assert_eq!(gradient([0, 0, 0], [255, 100, 10], 2.0), [255, 100, 10]);
+    }
+}
@@ -2,18 +2,67 @@
 #![feature(associated_type_bounds)]
 use clap::Parser;
 use itertools::Itertools;
-use log::{debug, error, info};
+use log::{debug, error, info, LevelFilter};
+use serde::Serialize;
 use simple_logger::SimpleLogger;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
 use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 
-#[derive(Clone, PartialEq, Eq, Copy)]
+pub mod alloc;
+mod automaton;
+mod bitgrid;
+mod config;
+mod cube;
+mod cycle;
+mod diff;
+mod direction;
+mod grid3;
+mod image;
+mod pathfinding;
+mod point;
+mod ray;
+mod record;
+mod regions;
+mod render;
+mod repl;
+mod sparse_grid;
+mod trace;
+mod transform;
+mod view;
+mod wrap;
+pub use alloc::MemoryStats;
+pub use automaton::{Automaton, Cell, Rule, Space};
+pub use bitgrid::{BitGrid, BitGrid3};
+pub use config::{Config, OutputFormat};
+pub use cube::CubeNet;
+pub use cycle::{Cycle, CycleDetector};
+pub use diff::{Change, SideBySide};
+pub use direction::Direction;
+pub use grid3::{Bounds3, Grid3, Point3};
+pub use image::{gradient, Image, Rgb};
+pub use pathfinding::SearchResult;
+pub use point::Point;
+pub use ray::Hit;
+pub use record::{RecordFormat, Recorder};
+pub use regions::{Connectivity, Region, Regions};
+pub use render::{Render, Style};
+pub use repl::{param, set_param, unset_param, CommandResult, Repl};
+pub use sparse_grid::SparseGrid;
+pub use view::{GridView, GridViewMut};
+pub use wrap::Wrap;
+
+#[derive(Clone, PartialEq, Eq, Copy, Debug)]
 pub enum Part {
     One,
     Two,
@@ -43,7 +92,7 @@ impl Display for Part {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Copy)]
+#[derive(Clone, PartialEq, Eq, Copy, Debug)]
 pub enum Target {
     Sample(usize),
     Samples,
@@ -112,18 +161,156 @@ impl Target {
     }
 }
 
-#[derive(Parser, Copy, Clone)]
+/// A puzzle, identified by its calendar year and day number.
+#[derive(Clone, PartialEq, Eq, Copy, Debug, Hash, PartialOrd, Ord, Serialize)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Day {
+    pub const fn new(year: u16, day: u8) -> Day {
+        Day { year, day }
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+#[derive(Parser, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
-    #[arg(short, long, default_value_t = Part::All)]
-    pub part: Part,
-    #[arg(short, long, default_value_t = Target::All)]
-    pub target: Target,
+    #[arg(short, long)]
+    pub part: Option<Part>,
+    #[arg(short, long)]
+    pub target: Option<Target>,
+    /// Calendar whose inputs, samples and answers to use, defaults to the year
+    /// of the day being run
+    #[arg(short, long)]
+    pub year: Option<u16>,
+    /// Config file, defaults to `aoc.toml` in the working directory if present
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+    #[arg(short, long)]
+    pub log_level: Option<LevelFilter>,
+    #[arg(long)]
+    pub input_dir: Option<PathBuf>,
+    #[arg(long)]
+    pub sample_dir: Option<PathBuf>,
+    #[arg(long)]
+    pub answer_dir: Option<PathBuf>,
+    /// Run parts and inputs concurrently
+    #[arg(long, overrides_with = "no_parallel")]
+    pub parallel: bool,
+    /// Run parts and inputs one after another
+    #[arg(long, overrides_with = "parallel")]
+    pub no_parallel: bool,
+    /// Seconds a single run may take before the process is aborted
+    #[arg(long)]
+    pub timeout: Option<u64>,
+    #[arg(short, long)]
+    pub format: Option<OutputFormat>,
+    /// Explore a loaded input with the day's commands instead of running
+    #[arg(short, long)]
+    pub interactive: bool,
+    /// Write each run's log to a file under this directory
+    #[arg(long)]
+    pub trace_dir: Option<PathBuf>,
+    #[arg(long)]
+    pub trace_level: Option<LevelFilter>,
+    /// Only trace modules starting with this prefix, e.g. `2022_22`
+    #[arg(long = "trace-module", value_name = "PREFIX")]
+    pub trace_modules: Vec<String>,
+    /// Stop writing a trace file after this many bytes
+    #[arg(long)]
+    pub trace_max_bytes: Option<u64>,
+    /// Record the frames simulations push to `aoc::Recorder` under this directory
+    #[arg(long)]
+    pub record_dir: Option<PathBuf>,
+    #[arg(long)]
+    pub record_format: Option<RecordFormat>,
+    /// Pixels per cell in image recordings
+    #[arg(long)]
+    pub record_scale: Option<usize>,
+    /// Parameter read by solvers through `aoc::param`
+    #[arg(short = 'D', long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<String>,
 }
 
 pub trait InputResult: Display + Send + Sync + Eq + PartialEq + Debug + Clone {}
 impl<T> InputResult for T where T: Display + Send + Sync + Eq + PartialEq + Debug + Clone {}
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Passed,
+    Failed,
+    Unchecked,
+}
+
+/// Outcome of running one part against one input.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    #[serde(flatten)]
+    pub day: Day,
+    pub part: u8,
+    pub input: String,
+    pub result: String,
+    pub expected: Option<String>,
+    pub status: Status,
+    #[serde(serialize_with = "serialize_millis", rename = "elapsed_ms")]
+    pub elapsed: Duration,
+    /// Only measured when built with the `track-alloc` feature
+    #[serde(flatten)]
+    pub memory: Option<MemoryStats>,
+}
+
+fn serialize_millis<S: serde::Serializer>(elapsed: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_f64(elapsed.as_secs_f64() * 1000.0)
+}
+
+impl Report {
+    fn measurements(&self) -> String {
+        match self.memory {
+            Some(memory) => format!("{:?}, {}", self.elapsed, memory),
+            None => format!("{:?}", self.elapsed),
+        }
+    }
+
+    fn emit(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Json => println!("{}", serde_json::to_string(self).unwrap()),
+            OutputFormat::Text => match (self.status, &self.expected) {
+                (Status::Passed, _) => info!(
+                    "Part {} {} passed: {} ({})",
+                    self.part,
+                    self.input,
+                    self.result,
+                    self.measurements()
+                ),
+                (Status::Failed, Some(expected)) => error!(
+                    "Part {} {} failed : {} (expected {}, {})",
+                    self.part,
+                    self.input,
+                    self.result,
+                    expected,
+                    self.measurements()
+                ),
+                _ => info!(
+                    "Part {} {}: {} ({})",
+                    self.part,
+                    self.input,
+                    self.result,
+                    self.measurements()
+                ),
+            },
+        }
+    }
+}
+
 pub trait Solver<'a, D>: Sync
 where
     D: InputResult + Sync + 'a,
@@ -131,6 +318,10 @@ where
     fn solve_part_one(&self, lines: &[&str]) -> D;
     fn solve_part_two(&self, lines: &[&str]) -> D;
 
+    /// Registers commands for interactive mode, typically closures over
+    /// structures parsed from `lines`.
+    fn register_commands<'l>(&self, _lines: &'l [&'l str], _repl: &mut Repl<'l>) {}
+
     fn run_single(&self, solver: &(dyn Fn(&[&str]) -> D + Sync), lines: &[&str]) -> (D, Duration) {
         let start = Instant::now();
         let result = solver(lines);
@@ -142,74 +333,183 @@ where
         self.run_single(&|lines| self.solve_part_one(lines), lines)
     }
 
+    fn run_input<const PART: u8>(
+        &self,
+        solver: &(dyn Fn(&[&str]) -> D + Sync),
+        day: Day,
+        idx: usize,
+        input: &Input<D>,
+        config: &Config,
+    ) -> Report {
+        let data = input.load(day, config);
+        let lines = get_lines(&data);
+
+        let _trace = config
+            .trace_path(day, PART, &input.file_label(idx))
+            .map(|path| trace::start(&path, config.trace_max_bytes));
+        let _recording = config
+            .record_path(day, PART, &input.file_label(idx))
+            .map(|path| record::start(&path, config.record_format, config.record_scale));
+
+        let ((result, elapsed), memory) = match config.timeout() {
+            Some(timeout) => thread::scope(|s| {
+                let (done, finished) = mpsc::channel::<()>();
+                s.spawn(move || {
+                    if let Err(RecvTimeoutError::Timeout) = finished.recv_timeout(timeout) {
+                        error!(
+                            "Part {PART} {} timed out after {:?}",
+                            input.label(idx),
+                            timeout
+                        );
+                        process::exit(1);
+                    }
+                });
+                let outcome = alloc::measure(|| self.run_single(solver, lines.as_slice()));
+                drop(done);
+                outcome
+            }),
+            None => alloc::measure(|| self.run_single(solver, lines.as_slice())),
+        };
+
+        let expected = match &input.solution {
+            Some(solution) => Some(solution.to_string()),
+            None => input
+                .file_name()
+                .and_then(|name| config.answer(day, name, PART)),
+        };
+        let status = match &expected {
+            Some(expected) if *expected == result.to_string() => Status::Passed,
+            Some(_) => Status::Failed,
+            None => Status::Unchecked,
+        };
+
+        Report {
+            day,
+            part: PART,
+            input: input.label(idx),
+            result: result.to_string(),
+            expected,
+            status,
+            elapsed,
+            memory,
+        }
+    }
+
     fn run_all_for_solver<'b, const PART: u8>(
         &'a self,
         solver: &'b (dyn Fn(&[&str]) -> D + Sync),
+        day: Day,
         inputs: impl Iterator<Item = (usize, &'a Input<'a, D>)>,
+        config: &Config,
     ) {
-        thread::scope(|s| {
+        if config.parallel {
+            thread::scope(|s| {
+                for (idx, input) in inputs {
+                    s.spawn(move || {
+                        self.run_input::<PART>(solver, day, idx, input, config)
+                            .emit(config.format);
+                    });
+                }
+            })
+        } else {
             for (idx, input) in inputs {
-                s.spawn(move || {
-                    let (result, elapsed) =
-                        self.run_single(solver, get_lines(input.data).as_slice());
-                    if let Some(solution) = &input.solution {
-                        if solution == &result {
-                            info!(
-                                "Part {PART} sample #{idx} passed: {} ({:?})",
-                                result, elapsed
-                            );
-                        } else {
-                            error!(
-                                "Part {PART} sample #{idx} failed : {} (expected {}, {:?})",
-                                result, solution, elapsed
-                            );
-                        }
-                    } else {
-                        info!("Part {PART} final: {} ({:?})", result, elapsed);
-                    }
-                });
+                self.run_input::<PART>(solver, day, idx, input, config)
+                    .emit(config.format);
             }
-        })
+        }
     }
 
-    fn run_all_part_one(&'a self, inputs: impl Iterator<Item = (usize, &'a Input<'a, D>)>) {
-        self.run_all_for_solver::<1>(&|lines| self.solve_part_one(lines), inputs);
+    fn run_all_part_one(
+        &'a self,
+        day: Day,
+        inputs: impl Iterator<Item = (usize, &'a Input<'a, D>)>,
+        config: &Config,
+    ) {
+        self.run_all_for_solver::<1>(&|lines| self.solve_part_one(lines), day, inputs, config);
     }
 
     fn run_part_two(&self, lines: &[&str]) -> (D, Duration) {
         self.run_single(&|lines| self.solve_part_two(lines), lines)
     }
-    fn run_all_part_two(&'a self, inputs: impl Iterator<Item = (usize, &'a Input<'a, D>)>) {
-        self.run_all_for_solver::<2>(&|lines| self.solve_part_two(lines), inputs);
+    fn run_all_part_two(
+        &'a self,
+        day: Day,
+        inputs: impl Iterator<Item = (usize, &'a Input<'a, D>)>,
+        config: &Config,
+    ) {
+        self.run_all_for_solver::<2>(&|lines| self.solve_part_two(lines), day, inputs, config);
     }
 
-    fn run(&'a self, part_one_inputs: &'a [Input<D>], part_two_inputs: &'a [Input<D>]) {
+    fn run(&'a self, day: Day, part_one_inputs: &'a [Input<D>], part_two_inputs: &'a [Input<D>]) {
         let args = Cli::parse();
+        let config = Config::from_cli(&args, day).unwrap_or_else(|e| panic!("{}", e));
+        let day = Day::new(config.year, day.day);
 
-        SimpleLogger::new().env().init().unwrap();
-        thread::scope(|s| {
-            if args.part == Part::One || args.part == Part::All {
-                let part_one_inputs = args.target.filter_inputs(part_one_inputs);
-                s.spawn(|| {
-                    self.run_all_part_one(part_one_inputs);
-                });
+        // RUST_LOG overrides the config file, an explicit --log-level overrides both
+        let mut logger = SimpleLogger::new().with_level(config.log_level).env();
+        if let Some(log_level) = args.log_level {
+            logger = logger.with_level(log_level);
+        }
+
+        for param in &args.params {
+            let (name, value) = param
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Expected NAME=VALUE, got {}", param));
+            set_param(name, value);
+        }
+
+        if args.interactive {
+            // Let the REPL raise the level later, filtering through the global max instead
+            let max_level = logger.max_level();
+            logger.with_level(LevelFilter::Trace).init().unwrap();
+            log::set_max_level(max_level);
+            repl::interact(self, day, part_one_inputs, part_two_inputs, &config);
+            return;
+        }
+        trace::init(logger, &config);
+
+        let run_part_one = config.part == Part::One || config.part == Part::All;
+        let run_part_two = config.part == Part::Two || config.part == Part::All;
+        let config = &config;
+        if config.parallel {
+            thread::scope(|s| {
+                if run_part_one {
+                    let part_one_inputs = config.target.filter_inputs(part_one_inputs);
+                    s.spawn(|| {
+                        self.run_all_part_one(day, part_one_inputs, config);
+                    });
+                }
+                if run_part_two {
+                    let part_two_inputs = config.target.filter_inputs(part_two_inputs);
+                    s.spawn(|| {
+                        self.run_all_part_two(day, part_two_inputs, config);
+                    });
+                }
+            })
+        } else {
+            if run_part_one {
+                self.run_all_part_one(day, config.target.filter_inputs(part_one_inputs), config);
             }
-            if args.part == Part::Two || args.part == Part::All {
-                let part_two_inputs = args.target.filter_inputs(part_two_inputs);
-                s.spawn(|| {
-                    self.run_all_part_two(part_two_inputs);
-                });
+            if run_part_two {
+                self.run_all_part_two(day, config.target.filter_inputs(part_two_inputs), config);
             }
-        })
+        }
     }
 }
 
+#[derive(Eq, PartialEq, Debug, Clone)]
+enum Source<'a> {
+    Inline(&'a str),
+    SampleFile(&'a str),
+    FinalFile(&'a str),
+}
+
 #[derive(Eq, PartialEq, Debug, Clone)]
 pub struct Input<'a, D>
 where
     D: InputResult,
 {
-    data: &'a str,
+    source: Source<'a>,
     solution: Option<D>,
 }
 
@@ -219,23 +519,89 @@ where
 {
     pub fn new_sample(sample: &'a str, solution: D) -> Self {
         Self {
-            data: sample,
+            source: Source::Inline(sample),
             solution: Some(solution),
         }
     }
 
     pub fn new_final(input: &'a str) -> Self {
         Self {
-            data: input,
+            source: Source::Inline(input),
+            solution: None,
+        }
+    }
+
+    /// A sample read at run time from the day's year in the configured sample
+    /// directory.
+    pub fn sample_file(name: &'a str, solution: D) -> Self {
+        Self {
+            source: Source::SampleFile(name),
+            solution: Some(solution),
+        }
+    }
+
+    /// A puzzle input read at run time from the day's year in the configured
+    /// input directory.
+    /// Its answers are checked against the answer directory if present.
+    pub fn final_file(name: &'a str) -> Self {
+        Self {
+            source: Source::FinalFile(name),
             solution: None,
         }
     }
+
+    fn file_name(&self) -> Option<&'a str> {
+        match self.source {
+            Source::FinalFile(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn file_label(&self, idx: usize) -> String {
+        if self.solution.is_some() {
+            format!("sample{idx}")
+        } else {
+            "final".to_string()
+        }
+    }
+
+    fn label(&self, idx: usize) -> String {
+        if self.solution.is_some() {
+            format!("sample #{idx}")
+        } else {
+            "final".to_string()
+        }
+    }
+
+    fn load(&self, day: Day, config: &Config) -> Cow<'a, str> {
+        let path = match self.source {
+            Source::Inline(data) => return Cow::Borrowed(data),
+            Source::SampleFile(name) => config.sample_path(day, name),
+            Source::FinalFile(name) => config.input_path(day, name),
+        };
+        Cow::Owned(
+            fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Could not read input {}: {e}", path.display())),
+        )
+    }
 }
 
 fn get_lines(file: &str) -> Vec<&str> {
     file.lines().collect_vec()
 }
 
+const CARDINAL_OFFSETS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
 #[derive(Eq, PartialEq, Debug, Clone)]
 pub struct Grid<T> {
     pub state: Vec<T>,
@@ -309,6 +675,49 @@ impl<T: Default + Clone> Grid<T> {
         let index = self.pos_to_index(pos);
         &mut self.state[index]
     }
+    /// Position `offset` away from `pos`, if it is inside the grid.
+    pub fn offset_pos(
+        &self,
+        pos: (usize, usize),
+        offset: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = pos.0.checked_add_signed(offset.0)?;
+        let y = pos.1.checked_add_signed(offset.1)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    fn neighbors_at_offsets<'a>(
+        &'a self,
+        pos: (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        offsets.iter().filter_map(move |&offset| {
+            self.offset_pos(pos, offset)
+                .map(|neighbor| (neighbor, self.at(neighbor)))
+        })
+    }
+
+    /// In-bounds cells above, left of, right of and below `pos`.
+    pub fn enumerate_cardinal_neighbors(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors_at_offsets(pos, &CARDINAL_OFFSETS)
+    }
+
+    /// In-bounds cells among the 8 surrounding `pos`, row by row.
+    pub fn enumerate_neighbors(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors_at_offsets(pos, &NEIGHBOR_OFFSETS)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.state.iter())
+    }
+
     pub fn cardinal_neighbor_positions(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
         let (x, y) = (pos.0 as i64, pos.1 as i64);
         let neighbors = [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)];
@@ -371,6 +780,7 @@ impl<T: Default + Clone> Grid<T> {
             })
     }
 
+    #[deprecated(note = "use `Grid::ray` to the left and right")]
     pub fn horizontal_neighbors(
         &self,
         pos: (usize, usize),
@@ -384,6 +794,7 @@ impl<T: Default + Clone> Grid<T> {
         (left_half, right_half)
     }
 
+    #[deprecated(note = "use `Grid::ray` up and down")]
     pub fn vertical_neighbors(
         &self,
         pos: (usize, usize),
@@ -397,6 +808,7 @@ impl<T: Default + Clone> Grid<T> {
         (top_half, bottom_half)
     }
 
+    #[deprecated(note = "use `Grid::ray` in each of `Direction::ALL`")]
     pub fn neighbors_along_directions(
         &self,
         pos: (usize, usize),
@@ -473,12 +885,121 @@ impl<T: Default + Clone> Grid<T> {
             height,
         }
     }
+
+    /// Like `from_lines`, but returns an error for empty input, for rows of
+    /// different lengths and for characters `transformer` rejects. With `pad`
+    /// set, short rows are filled up to the longest row instead.
+    pub fn try_from_lines<E: Display>(
+        lines: &[&str],
+        pad: Option<T>,
+        transformer: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, String> {
+        Self::try_from_lines_with_markers(lines, pad, &[], transformer).map(|(grid, _)| grid)
+    }
+
+    /// Like `from_lines`, but the characters in `markers`, like a start and
+    /// an end, have their positions recorded and are stored as the value
+    /// paired with them instead of going through `transformer`.
+    pub fn from_lines_with_markers(
+        lines: &[&str],
+        markers: &[(char, T)],
+        transformer: &dyn Fn(char) -> T,
+    ) -> (Grid<T>, Markers) {
+        Self::try_from_lines_with_markers(lines, None, markers, |c| Ok::<_, &str>(transformer(c)))
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// `try_from_lines` with the `markers` of `from_lines_with_markers`.
+    pub fn try_from_lines_with_markers<E: Display>(
+        lines: &[&str],
+        pad: Option<T>,
+        markers: &[(char, T)],
+        transformer: impl Fn(char) -> Result<T, E>,
+    ) -> Result<(Grid<T>, Markers), String> {
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        if width == 0 {
+            return Err("Grid is empty".to_string());
+        }
+
+        let height = lines.len();
+        let mut state = Vec::with_capacity(width * height);
+        let mut found = Markers::default();
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let value = match markers.iter().find(|(marker, _)| *marker == c) {
+                    Some((_, value)) => {
+                        found.0.entry(c).or_default().push((x, y));
+                        value.clone()
+                    }
+                    None => transformer(c).map_err(|e| {
+                        format!("Invalid character {:?} at ({}, {}): {}", c, x, y, e)
+                    })?,
+                };
+                state.push(value);
+            }
+            let len = line.chars().count();
+            if len < width {
+                match &pad {
+                    Some(fill) => state.extend(std::iter::repeat_n(fill.clone(), width - len)),
+                    None => {
+                        return Err(format!(
+                            "Row {} has {} characters, expected {}",
+                            y, len, width
+                        ))
+                    }
+                }
+            }
+        }
+        let grid = Grid {
+            state,
+            width,
+            height,
+        };
+        Ok((grid, found))
+    }
+}
+
+/// Where the marker characters passed to `Grid::from_lines_with_markers`
+/// were found, in reading order.
+#[derive(Eq, PartialEq, Debug, Clone, Default)]
+pub struct Markers(HashMap<char, Vec<(usize, usize)>>);
+
+impl Markers {
+    /// Every position of `marker`, empty if it was not found.
+    pub fn positions(&self, marker: char) -> &[(usize, usize)] {
+        self.0.get(&marker).map_or(&[], |positions| positions)
+    }
+
+    pub fn first(&self, marker: char) -> Option<(usize, usize)> {
+        self.positions(marker).first().copied()
+    }
+
+    pub fn last(&self, marker: char) -> Option<(usize, usize)> {
+        self.positions(marker).last().copied()
+    }
+
+    /// The position of a marker expected exactly once, like a start.
+    pub fn single(&self, marker: char) -> Result<(usize, usize), String> {
+        match self.positions(marker) {
+            [pos] => Ok(*pos),
+            positions => Err(format!(
+                "Expected one {:?}, found {}",
+                marker,
+                positions.len()
+            )),
+        }
+    }
 }
 
 impl<T: Display> Display for Grid<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f)?;
-        for chunk in self.state.chunks(self.width) {
+        // Grids with no columns have no cells to split into rows
+        for chunk in self.state.chunks(self.width.max(1)) {
             for t in chunk {
                 write!(f, "{t}")?;
             }
@@ -582,3 +1103,74 @@ where
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_lines() {
+        let parse = |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected # or ."),
+        };
+
+        let grid = Grid::try_from_lines(&["#.", "..#"], Some(false), parse).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.state, vec![true, false, false, false, false, true]);
+
+        assert_eq!(
+            Grid::try_from_lines(&["#.", "..#"], None, parse),
+            Err("Row 0 has 2 characters, expected 3".to_string())
+        );
+        assert_eq!(
+            Grid::try_from_lines(&["#.", ".x"], None, parse),
+            Err("Invalid character 'x' at (1, 1): expected # or .".to_string())
+        );
+        assert!(Grid::try_from_lines(&[], None, parse).is_err());
+        assert!(Grid::try_from_lines(&["", ""], Some(false), parse).is_err());
+    }
+
+    #[test]
+    fn test_from_lines_with_markers() {
+        let (grid, markers) = Grid::from_lines_with_markers(
+            &["S.#", "#.E", "..E"],
+            &[('S', '.'), ('E', '.')],
+            &|c| c,
+        );
+        assert_eq!(grid, Grid::from_lines(&["..#", "#..", "..."], &|c| c));
+        assert_eq!(markers.single('S'), Ok((0, 0)));
+        assert_eq!(markers.positions('E'), [(2, 1), (2, 2)]);
+        assert_eq!(markers.last('E'), Some((2, 2)));
+        assert!(markers.single('E').is_err());
+        assert_eq!(markers.first('x'), None);
+
+        let (_, markers) =
+            Grid::try_from_lines_with_markers(&["#", ".#S"], Some(true), &[('S', false)], |c| {
+                match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err("expected # or ."),
+                }
+            })
+            .unwrap();
+        assert_eq!(markers.single('S'), Ok((2, 1)));
+    }
+
+    #[test]
+    fn test_enumerate_neighbors() {
+        let grid = Grid::from_lines(&["abc", "def"], &|c| c);
+        assert_eq!(
+            grid.enumerate_cardinal_neighbors((0, 0)).collect_vec(),
+            vec![((1, 0), &'b'), ((0, 1), &'d')]
+        );
+        assert_eq!(
+            grid.enumerate_neighbors((1, 1))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "abcdf"
+        );
+        assert_eq!(grid.enumerate().nth(4), Some(((1, 1), &'e')));
+    }
+}
@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use crate::Grid;
+
+type Pos = (usize, usize);
+
+/// Distances found by a search over a `Grid`, with enough bookkeeping to
+/// rebuild the path to any reached cell.
+#[derive(Clone, Debug, Default)]
+pub struct SearchResult {
+    /// Cost from the nearest source to each reached cell.
+    pub distances: HashMap<Pos, usize>,
+    previous: HashMap<Pos, Pos>,
+    /// The first target reached, if any.
+    pub target: Option<Pos>,
+}
+
+impl SearchResult {
+    /// Cost to the reached target.
+    pub fn distance(&self) -> Option<usize> {
+        self.target.and_then(|target| self.distance_to(target))
+    }
+
+    pub fn distance_to(&self, pos: Pos) -> Option<usize> {
+        self.distances.get(&pos).copied()
+    }
+
+    /// Cells from a source to the reached target, both included.
+    pub fn path(&self) -> Option<Vec<Pos>> {
+        self.target.and_then(|target| self.path_to(target))
+    }
+
+    pub fn path_to(&self, pos: Pos) -> Option<Vec<Pos>> {
+        if !self.distances.contains_key(&pos) {
+            return None;
+        }
+        let mut path = vec![pos];
+        while let Some(&prev) = self.previous.get(path.last().unwrap()) {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Searches move between cardinal neighbours. With no targets, every reachable
+// cell is explored.
+impl<T: Default + Clone> Grid<T> {
+    /// Breadth-first search where each step costs 1 and `passable(from, to)`
+    /// decides whether a step is allowed.
+    pub fn bfs(
+        &self,
+        sources: &[Pos],
+        targets: &[Pos],
+        passable: impl Fn(&T, &T) -> bool,
+    ) -> SearchResult {
+        let targets: HashSet<_> = targets.iter().copied().collect();
+        let mut result = SearchResult::default();
+        let mut queue = VecDeque::new();
+        for &source in sources {
+            if result.distances.insert(source, 0).is_none() {
+                queue.push_back(source);
+            }
+        }
+
+        while let Some(pos) = queue.pop_front() {
+            if targets.contains(&pos) {
+                result.target = Some(pos);
+                break;
+            }
+            let distance = result.distances[&pos];
+            let from = self.at(pos);
+            for (next, to) in self.enumerate_cardinal_neighbors(pos) {
+                if !result.distances.contains_key(&next) && passable(from, to) {
+                    result.distances.insert(next, distance + 1);
+                    result.previous.insert(next, pos);
+                    queue.push_back(next);
+                }
+            }
+        }
+        result
+    }
+
+    /// Cheapest paths where `cost(from, to)` is the price of a step, or
+    /// `None` if it is not allowed.
+    pub fn dijkstra(
+        &self,
+        sources: &[Pos],
+        targets: &[Pos],
+        cost: impl Fn(&T, &T) -> Option<usize>,
+    ) -> SearchResult {
+        self.astar(sources, targets, cost, |_| 0)
+    }
+
+    /// Like `dijkstra`, guided by `heuristic`, which must never overestimate
+    /// the remaining cost to the nearest target.
+    pub fn astar(
+        &self,
+        sources: &[Pos],
+        targets: &[Pos],
+        cost: impl Fn(&T, &T) -> Option<usize>,
+        heuristic: impl Fn(Pos) -> usize,
+    ) -> SearchResult {
+        let targets: HashSet<_> = targets.iter().copied().collect();
+        let mut result = SearchResult::default();
+        let mut heap = BinaryHeap::new();
+        for &source in sources {
+            result.distances.insert(source, 0);
+            heap.push(Reverse((heuristic(source), 0, source)));
+        }
+
+        while let Some(Reverse((_, distance, pos))) = heap.pop() {
+            if distance > result.distances[&pos] {
+                continue;
+            }
+            if targets.contains(&pos) {
+                result.target = Some(pos);
+                break;
+            }
+            let from = self.at(pos);
+            for (next, to) in self.enumerate_cardinal_neighbors(pos) {
+                let Some(step) = cost(from, to) else {
+                    continue;
+                };
+                let next_distance = distance + step;
+                if result
+                    .distances
+                    .get(&next)
+                    .is_none_or(|&known| next_distance < known)
+                {
+                    result.distances.insert(next, next_distance);
+                    result.previous.insert(next, pos);
+                    heap.push(Reverse((
+                        next_distance + heuristic(next),
+                        next_distance,
+                        next,
+                    )));
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> Grid<char> {
+        Grid::from_lines(&["S.#.", "..#E", "...."], &|c| c)
+    }
+
+    #[test]
+    fn test_bfs_path() {
+        let grid = maze();
+        let result = grid.bfs(&[(0, 0)], &[(3, 1)], |_, to| *to != '#');
+        assert_eq!(result.distance(), Some(6));
+        let path = result.path().unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!((path[0], path[6]), ((0, 0), (3, 1)));
+        assert!(path.iter().all(|&pos| *grid.at(pos) != '#'));
+
+        let everywhere = grid.bfs(&[(0, 0), (3, 0)], &[], |_, to| *to != '#');
+        assert_eq!(everywhere.target, None);
+        assert_eq!(everywhere.distance_to((3, 1)), Some(1));
+        assert_eq!(everywhere.distances.len(), 10);
+    }
+
+    #[test]
+    fn test_weighted_search() {
+        let grid = Grid::from_lines(&["1191", "9111", "1192"], &|c| c.to_digit(10).unwrap());
+        let cost = |_: &u32, to: &u32| Some(*to as usize);
+        let targets = [(3, 0), (3, 2)];
+        let dijkstra = grid.dijkstra(&[(0, 0)], &targets, cost);
+        let astar = grid.astar(&[(0, 0)], &targets, cost, |(x, _)| 3 - x);
+        assert_eq!(dijkstra.distance(), Some(5));
+        assert_eq!(astar.distance(), Some(5));
+        assert_eq!(
+            astar.path().unwrap(),
+            vec![(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (3, 0)]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Direction;
+
+/// Signed 2D position or offset. `y` grows downwards, matching `Grid` rows.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: Point) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+    }
+
+    pub fn chebyshev(&self, other: Point) -> usize {
+        (self.x - other.x).abs().max((self.y - other.y).abs()) as usize
+    }
+
+    /// Moves one step in `direction`.
+    pub fn step(&self, direction: Direction) -> Point {
+        *self + direction.offset()
+    }
+
+    /// Offset with each component reduced to -1, 0 or 1, e.g. for a rope
+    /// knot following its leader.
+    pub fn signum(&self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn cardinal_neighbors(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Direction::CARDINAL.iter().map(move |d| point.step(*d))
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        Direction::ALL.iter().map(move |d| point.step(*d))
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+/// Converts to a `Grid` position, failing for negative coordinates.
+impl TryFrom<Point> for (usize, usize) {
+    type Error = Point;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        if point.x >= 0 && point.y >= 0 {
+            Ok((point.x as usize, point.y as usize))
+        } else {
+            Err(point)
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self.step(direction)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: isize) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a + b, Point::new(-2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(-a * 3, Point::new(-3, 6));
+        assert_eq!((a - b).signum(), Point::new(1, -1));
+        assert_eq!(a + Direction::Up, Point::new(1, -3));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert!(a.neighbors().all(|n| n.chebyshev(a) == 1));
+        assert!(a.cardinal_neighbors().all(|n| n.manhattan(a) == 1));
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        assert_eq!(<(usize, usize)>::try_from(Point::new(2, 3)), Ok((2, 3)));
+        assert!(<(usize, usize)>::try_from(Point::new(-1, 3)).is_err());
+        assert_eq!(Point::from((2_usize, 3_usize)), Point::new(2, 3));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Direction, Grid};
+
+type Pos = (usize, usize);
+
+/// Where a ray cast from a cell stopped.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Hit {
+    /// The cell that stopped the ray, or the last cell before the edge.
+    pub pos: Pos,
+    /// Steps from the starting cell to `pos`.
+    pub distance: usize,
+    /// Whether a cell stopped the ray rather than the edge of the grid.
+    pub blocked: bool,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Cells from `pos` outwards in `direction` up to the edge, without `pos`.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        let offset = direction.offset().into();
+        std::iter::successors(self.offset_pos(pos, offset), move |&next| {
+            self.offset_pos(next, offset)
+        })
+        .map(move |next| (next, self.at(next)))
+    }
+
+    /// Walks from `pos` in `direction` until `stop` holds for a cell or the
+    /// ray reaches the edge of the grid.
+    pub fn cast(&self, pos: Pos, direction: Direction, stop: impl Fn(&T) -> bool) -> Hit {
+        let mut hit = Hit {
+            pos,
+            distance: 0,
+            blocked: false,
+        };
+        for (next, value) in self.ray(pos, direction) {
+            hit.pos = next;
+            hit.distance += 1;
+            if stop(value) {
+                hit.blocked = true;
+                break;
+            }
+        }
+        hit
+    }
+
+    /// Casts a ray in all 8 directions, clockwise from `Up`.
+    pub fn cast_all(&self, pos: Pos, stop: impl Fn(&T) -> bool) -> [Hit; 8] {
+        Direction::ALL.map(|direction| self.cast(pos, direction, &stop))
+    }
+}
+
+impl<T: Default + Clone + Ord> Grid<T> {
+    /// Cells seen from outside the grid looking along one of `directions`:
+    /// those higher than every cell in front of them. Each direction sweeps
+    /// every line once, keeping the highest cell so far.
+    pub fn visible_from(&self, directions: &[Direction]) -> Grid<bool> {
+        let mut visible = Grid::new_empty(self.width, self.height);
+        for &direction in directions {
+            let back = (-direction.offset()).into();
+            // Lines start at the cells with nothing in front of them
+            for start in self
+                .positions()
+                .filter(|&pos| self.offset_pos(pos, back).is_none())
+            {
+                let mut highest = self.at(start);
+                *visible.mut_at(start) = true;
+                for (pos, value) in self.ray(start, direction) {
+                    if value > highest {
+                        highest = value;
+                        *visible.mut_at(pos) = true;
+                    }
+                }
+            }
+        }
+        visible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::from_lines(&["30373", "25512", "65332", "33549", "35390"], &|c| {
+            c.to_digit(10).unwrap() as u8
+        })
+    }
+
+    #[test]
+    fn test_cast() {
+        let grid = grid();
+        // The 5 at (2, 3) is hidden by a tree of the same height above it
+        let taller = |tree: &u8| *tree >= 5;
+        let hit = grid.cast((2, 3), Direction::Up, taller);
+        assert_eq!(
+            hit,
+            Hit {
+                pos: (2, 1),
+                distance: 2,
+                blocked: true
+            }
+        );
+        let hit = grid.cast((2, 3), Direction::Left, taller);
+        assert_eq!(
+            hit,
+            Hit {
+                pos: (0, 3),
+                distance: 2,
+                blocked: false
+            }
+        );
+        assert_eq!(grid.cast((0, 0), Direction::UpLeft, |_| true).distance, 0);
+
+        let distances = grid.cast_all((2, 2), |_| false).map(|hit| hit.distance);
+        assert_eq!(distances, [2, 2, 2, 2, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_visible_from() {
+        let visible = grid().visible_from(&Direction::CARDINAL);
+        assert_eq!(visible.state.iter().filter(|v| **v).count(), 21);
+        assert!(!visible.at((2, 2)));
+
+        let diagonal = grid().visible_from(&[Direction::DownRight]);
+        let seen = [(4, 0), (0, 4), (1, 1), (2, 2)].map(|pos| *diagonal.at(pos));
+        assert_eq!(seen, [true, true, true, false]);
+    }
+}
@@ -0,0 +1,419 @@
+use log::{error, info};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{Grid, Image, Point, Rgb};
+
+/// Cells not covered by a frame's points.
+const BACKGROUND: char = '.';
+/// Time between frames, in hundredths of a second.
+const FRAME_DELAY: u16 = 10;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RecordFormat {
+    /// asciicast v2, for `asciinema play`
+    #[default]
+    Cast,
+    Gif,
+    /// One PNG per frame in a directory
+    Png,
+}
+
+impl FromStr for RecordFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cast" => Ok(RecordFormat::Cast),
+            "gif" => Ok(RecordFormat::Gif),
+            "png" => Ok(RecordFormat::Png),
+            _ => Err(format!("Unknown record format {s}")),
+        }
+    }
+}
+
+impl Display for RecordFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordFormat::Cast => write!(f, "cast"),
+            RecordFormat::Gif => write!(f, "gif"),
+            RecordFormat::Png => write!(f, "png"),
+        }
+    }
+}
+
+impl RecordFormat {
+    /// Extension of the recording, empty for a directory of images.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordFormat::Cast => ".cast",
+            RecordFormat::Gif => ".gif",
+            RecordFormat::Png => "",
+        }
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Collects the frames of a simulation while `--record-dir` is set. Frames
+/// pushed on a thread that is not recording a run are dropped, so days can
+/// record unconditionally and check `is_active` only to skip expensive
+/// rendering. Only the cells that change between frames are kept, and the
+/// recording is drawn one frame at a time when the run ends.
+pub struct Recorder {
+    path: PathBuf,
+    format: RecordFormat,
+    scale: usize,
+    palette: HashMap<char, Rgb>,
+    /// Cells of the last frame pushed
+    last: HashMap<Point, char>,
+    /// Cells each frame changes from the one before, starting from an empty
+    /// canvas. Cells a frame no longer covers change to the background.
+    changes: Vec<Vec<(Point, char)>>,
+}
+
+impl Recorder {
+    fn with(f: impl FnOnce(&mut Recorder)) {
+        RECORDER.with(|recorder| {
+            if let Some(recorder) = recorder.borrow_mut().as_mut() {
+                f(recorder);
+            }
+        });
+    }
+
+    /// Whether the current run is being recorded.
+    pub fn is_active() -> bool {
+        RECORDER.with(|recorder| recorder.borrow().is_some())
+    }
+
+    /// Pushes a frame showing every cell of `grid` as `symbol` of its value.
+    pub fn grid<T: Default + Clone>(grid: &Grid<T>, symbol: impl Fn(&T) -> char) {
+        Recorder::with(|recorder| {
+            let frame = grid
+                .enumerate()
+                .map(|(pos, t)| (Point::from(pos), symbol(t)))
+                .collect();
+            recorder.push(frame);
+        });
+    }
+
+    /// Pushes a frame of the given cells. Frames may cover different areas,
+    /// the recording spans all of them.
+    pub fn points<P: Into<Point>>(cells: impl IntoIterator<Item = (P, char)>) {
+        Recorder::with(|recorder| {
+            let frame = cells.into_iter().map(|(p, c)| (p.into(), c)).collect();
+            recorder.push(frame);
+        });
+    }
+
+    /// Colour of `symbol` in image recordings, replacing the default.
+    pub fn color(symbol: char, rgb: Rgb) {
+        Recorder::with(|recorder| {
+            recorder.palette.insert(symbol, rgb);
+        });
+    }
+
+    fn push(&mut self, frame: HashMap<Point, char>) {
+        let mut changes: Vec<_> = frame
+            .iter()
+            .filter(|&(p, c)| self.last.get(p) != Some(c))
+            .map(|(&p, &c)| (p, c))
+            .collect();
+        changes.extend(
+            self.last
+                .keys()
+                .filter(|p| !frame.contains_key(p))
+                .map(|&p| (p, BACKGROUND)),
+        );
+        self.changes.push(changes);
+        self.last = frame;
+    }
+
+    fn rgb(&self, symbol: char) -> Rgb {
+        if let Some(&rgb) = self.palette.get(&symbol) {
+            return rgb;
+        }
+        match symbol {
+            BACKGROUND | ' ' => [16, 16, 16],
+            '#' => [200, 200, 200],
+            // Any other symbol gets an arbitrary but stable bright colour
+            _ => {
+                let [r, g, b, _] = (symbol as u32).wrapping_mul(2_654_435_761).to_le_bytes();
+                [r | 0x40, g | 0x40, b | 0x40]
+            }
+        }
+    }
+
+    /// Top left corner, width and height of a canvas covering every frame.
+    fn area(&self) -> Option<(Point, usize, usize)> {
+        let points = || self.changes.iter().flatten().map(|(p, _)| *p);
+        let min_x = points().map(|p| p.x).min()?;
+        let max_x = points().map(|p| p.x).max()?;
+        let min_y = points().map(|p| p.y).min()?;
+        let max_y = points().map(|p| p.y).max()?;
+        let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+        Some((Point::new(min_x, min_y), width, height))
+    }
+
+    /// Draws every frame in turn on one canvas covering all of them.
+    fn replay(&self, mut draw: impl FnMut(usize, &Grid<char>) -> io::Result<()>) -> io::Result<()> {
+        let Some((corner, width, height)) = self.area() else {
+            return Ok(());
+        };
+        let mut canvas = Grid::new(vec![BACKGROUND; width * height], width, height);
+        for (idx, changes) in self.changes.iter().enumerate() {
+            for &(p, c) in changes {
+                *canvas.mut_at(((p.x - corner.x) as usize, (p.y - corner.y) as usize)) = c;
+            }
+            draw(idx, &canvas)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        match self.format {
+            RecordFormat::Cast => {
+                let mut out = BufWriter::new(File::create(&self.path)?);
+                self.replay(|idx, canvas| asciicast(&mut out, idx, canvas))?;
+                out.flush()
+            }
+            RecordFormat::Gif => {
+                let size = self.gif_size()?;
+                let symbols = self.symbols();
+                let mut out = BufWriter::new(File::create(&self.path)?);
+                self.gif_header(&mut out, size, &symbols)?;
+                self.replay(|_, canvas| self.gif_frame(&mut out, size, &symbols, canvas))?;
+                out.write_all(&[0x3b])?;
+                out.flush()
+            }
+            RecordFormat::Png => {
+                fs::create_dir_all(&self.path)?;
+                self.replay(|idx, canvas| {
+                    let image = Image::from_grid(canvas, self.scale, |&c| self.rgb(c));
+                    image.save(&self.path.join(format!("{idx:05}.png")))
+                })
+            }
+        }
+    }
+
+    /// Width and height of the GIF in pixels, which the format stores in 16
+    /// bits each.
+    fn gif_size(&self) -> io::Result<(u16, u16)> {
+        let (_, width, height) = self.area().unwrap_or((Point::new(0, 0), 1, 1));
+        let (width, height) = (width * self.scale, height * self.scale);
+        match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(width), Ok(height)) => Ok((width, height)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{width}x{height} pixels is too large for a GIF"),
+            )),
+        }
+    }
+
+    /// The background and the other symbols in the recording in order, as
+    /// many as fit in the GIF colour table.
+    fn symbols(&self) -> Vec<char> {
+        let others = self.changes.iter().flatten().map(|&(_, c)| c);
+        let others: BTreeSet<_> = others.filter(|&c| c != BACKGROUND).collect();
+        std::iter::once(BACKGROUND)
+            .chain(others)
+            .take(256)
+            .collect()
+    }
+
+    fn gif_header(
+        &self,
+        out: &mut impl Write,
+        (width, height): (u16, u16),
+        symbols: &[char],
+    ) -> io::Result<()> {
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        out.write_all(&[0xf7, 0, 0])?; // Global 256 colour table
+        for idx in 0..256 {
+            let rgb = symbols.get(idx).map_or([0; 3], |&c| self.rgb(c));
+            out.write_all(&rgb)?;
+        }
+        // Loop forever
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")
+    }
+
+    fn gif_frame(
+        &self,
+        out: &mut impl Write,
+        (width, height): (u16, u16),
+        symbols: &[char],
+        canvas: &Grid<char>,
+    ) -> io::Result<()> {
+        let index = |c: &char| symbols.iter().position(|s| s == c).unwrap_or(0) as u8;
+        out.write_all(&[0x21, 0xf9, 4, 0])?;
+        out.write_all(&FRAME_DELAY.to_le_bytes())?;
+        out.write_all(&[0, 0, 0x2c, 0, 0, 0, 0])?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        out.write_all(&[0])?;
+        let pixels = Image::from_grid(canvas, self.scale, |c| [index(c), 0, 0]).pixels;
+        out.write_all(&[8])?;
+        for block in lzw_literals(pixels.iter().map(|p| p[0])).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0])
+    }
+}
+
+/// Writes one frame of an asciicast, after the header for the first.
+fn asciicast(out: &mut impl Write, idx: usize, canvas: &Grid<char>) -> io::Result<()> {
+    if idx == 0 {
+        let header =
+            serde_json::json!({"version": 2, "width": canvas.width, "height": canvas.height});
+        writeln!(out, "{header}")?;
+    }
+    let rows = canvas
+        .state
+        .chunks(canvas.width)
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>();
+    let text = format!("\x1b[H\x1b[2J{}", rows.join("\r\n"));
+    let time = idx as f64 * FRAME_DELAY as f64 / 100.0;
+    writeln!(out, "{}", serde_json::json!([time, "o", text]))
+}
+
+/// GIF image data with 8 bit minimum code size that only ever emits literal
+/// codes, clearing the table before codes would need to grow past 9 bits.
+fn lzw_literals(indices: impl Iterator<Item = u8>) -> Vec<u8> {
+    const CLEAR: u32 = 256;
+    const END: u32 = 257;
+    let mut out = vec![];
+    let (mut bits, mut held) = (0u32, 0u32);
+    let mut emit = |code: u32| {
+        bits |= code << held;
+        held += 9;
+        while held >= 8 {
+            out.push(bits as u8);
+            bits >>= 8;
+            held -= 8;
+        }
+    };
+    for (idx, index) in indices.enumerate() {
+        if idx % 250 == 0 {
+            emit(CLEAR);
+        }
+        emit(index as u32);
+    }
+    emit(END);
+    if held > 0 {
+        out.push(bits as u8);
+    }
+    out
+}
+
+/// Writes the frames recorded on this thread when dropped.
+pub(crate) struct RecordGuard;
+
+impl Drop for RecordGuard {
+    fn drop(&mut self) {
+        let Some(recorder) = RECORDER.with(|recorder| recorder.borrow_mut().take()) else {
+            return;
+        };
+        if recorder.changes.is_empty() {
+            return;
+        }
+        match recorder.finish() {
+            Ok(()) => info!(
+                "Recorded {} frames to {}",
+                recorder.changes.len(),
+                recorder.path.display()
+            ),
+            Err(e) => error!("Could not write {}: {e}", recorder.path.display()),
+        }
+    }
+}
+
+pub(crate) fn start(path: &Path, format: RecordFormat, scale: usize) -> RecordGuard {
+    RECORDER.with(|recorder| {
+        *recorder.borrow_mut() = Some(Recorder {
+            path: path.to_path_buf(),
+            format,
+            scale,
+            palette: HashMap::new(),
+            last: HashMap::new(),
+            changes: vec![],
+        })
+    });
+    RecordGuard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_share_a_canvas() {
+        let _guard = start(Path::new("unused"), RecordFormat::Cast, 1);
+        Recorder::points([((0isize, 0isize), 'H'), ((1, 0), 'T')]);
+        Recorder::points([(Point::new(-1, 1), 'H')]);
+        Recorder::points([(Point::new(-1, 1), 'H')]);
+        let recorder = RECORDER.with(|r| r.borrow_mut().take().unwrap());
+        // Only changed cells are kept, with cells left behind cleared
+        assert_eq!(
+            recorder.changes.iter().map(Vec::len).collect::<Vec<_>>(),
+            [2, 3, 0]
+        );
+
+        let mut canvases = vec![];
+        let mut cast = vec![];
+        recorder
+            .replay(|idx, canvas| {
+                canvases.push(canvas.to_string());
+                asciicast(&mut cast, idx, canvas)
+            })
+            .unwrap();
+        assert_eq!(canvases[0], "\n.HT\n...\n\n");
+        assert_eq!(canvases[1], "\n...\nH..\n\n");
+        assert_eq!(canvases[2], canvases[1]);
+
+        let cast = String::from_utf8(cast).unwrap();
+        let lines = cast.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains("\"width\":3"));
+        assert!(lines[2].starts_with("[0.1,\"o\","));
+        assert!(!Recorder::is_active());
+    }
+
+    #[test]
+    fn test_gif_too_large() {
+        let _guard = start(Path::new("unused"), RecordFormat::Gif, 2);
+        Recorder::points([((0isize, 0isize), '#'), ((40_000, 0), '#')]);
+        let recorder = RECORDER.with(|r| r.borrow_mut().take().unwrap());
+        let error = recorder.gif_size().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_lzw_literals() {
+        // Clear, 'A', end, as 9 bit codes packed least significant bit first
+        assert_eq!(
+            lzw_literals(std::iter::once(0x41)),
+            [0x00, 0x83, 0x04, 0x04]
+        );
+        // Two clears, 500 literals and an end code, all 9 bits wide
+        assert_eq!(
+            lzw_literals(std::iter::repeat_n(0, 500)).len(),
+            (503 * 9usize).div_ceil(8)
+        );
+    }
+}
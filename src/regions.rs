@@ -0,0 +1,146 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{Grid, CARDINAL_OFFSETS, NEIGHBOR_OFFSETS};
+
+type Pos = (usize, usize);
+
+/// Which cells count as adjacent when growing a region.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Connectivity {
+    /// Up, down, left and right.
+    Four,
+    /// Diagonals as well.
+    Eight,
+}
+
+impl Connectivity {
+    pub(crate) fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &CARDINAL_OFFSETS,
+            Connectivity::Eight => &NEIGHBOR_OFFSETS,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Region {
+    pub size: usize,
+    /// Inclusive top left corner of the bounding box.
+    pub min: Pos,
+    /// Inclusive bottom right corner of the bounding box.
+    pub max: Pos,
+}
+
+/// Connected regions of a grid. `labels` holds the index into `regions` of
+/// every member cell and `None` elsewhere.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Regions {
+    pub labels: Grid<Option<usize>>,
+    pub regions: Vec<Region>,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Cells connected to `seed` through cells matching `member`, including
+    /// `seed` itself if it matches.
+    pub fn flood_fill(
+        &self,
+        seed: Pos,
+        connectivity: Connectivity,
+        member: impl Fn(&T) -> bool,
+    ) -> HashSet<Pos> {
+        let mut filled = HashSet::new();
+        if !self.get(seed).is_some_and(&member) {
+            return filled;
+        }
+        let mut queue = VecDeque::new();
+        filled.insert(seed);
+        queue.push_back(seed);
+        while let Some(pos) = queue.pop_front() {
+            for (next, value) in self.neighbors_at_offsets(pos, connectivity.offsets()) {
+                if member(value) && filled.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Labels every connected group of cells matching `member`, numbering
+    /// regions in the order their first cell appears row by row.
+    pub fn label_regions(
+        &self,
+        connectivity: Connectivity,
+        member: impl Fn(&T) -> bool,
+    ) -> Regions {
+        let mut labels: Grid<Option<usize>> = Grid::new_empty(self.width, self.height);
+        let mut regions = Vec::new();
+        for pos in self.positions() {
+            if labels.at(pos).is_some() || !member(self.at(pos)) {
+                continue;
+            }
+            let label = regions.len();
+            let mut region = Region {
+                size: 0,
+                min: pos,
+                max: pos,
+            };
+            for (x, y) in self.flood_fill(pos, connectivity, &member) {
+                *labels.mut_at((x, y)) = Some(label);
+                region.size += 1;
+                region.min = (region.min.0.min(x), region.min.1.min(y));
+                region.max = (region.max.0.max(x), region.max.1.max(y));
+            }
+            regions.push(region);
+        }
+        Regions { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_regions() {
+        let grid = Grid::from_lines(&["##..", "#..#", "..##", ".#.."], &|c| c == '#');
+
+        let four = grid.label_regions(Connectivity::Four, |wall| *wall);
+        assert_eq!(four.regions.len(), 3);
+        assert_eq!(
+            four.regions[0],
+            Region {
+                size: 3,
+                min: (0, 0),
+                max: (1, 1)
+            }
+        );
+        assert_eq!(four.regions[1].size, 3);
+        assert_eq!(four.regions[1].min, (2, 1));
+        assert_eq!(*four.labels.at((1, 3)), Some(2));
+        assert_eq!(*four.labels.at((2, 0)), None);
+
+        let eight = grid.label_regions(Connectivity::Eight, |wall| *wall);
+        assert_eq!(eight.regions.len(), 2);
+        assert_eq!(eight.regions[0].size, 3);
+        assert_eq!(eight.regions[1].size, 4);
+        assert_eq!(eight.regions[1].max, (3, 3));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::from_lines(&["..#", ".#.", "#.."], &|c| c == '#');
+        assert_eq!(
+            grid.flood_fill((0, 0), Connectivity::Four, |wall| !wall)
+                .len(),
+            3
+        );
+        assert_eq!(
+            grid.flood_fill((0, 0), Connectivity::Eight, |wall| !wall)
+                .len(),
+            6
+        );
+        assert!(grid
+            .flood_fill((2, 0), Connectivity::Four, |wall| !wall)
+            .is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::Display;
+use std::io::{self, IsTerminal};
+
+use crate::{Grid, GridView, Rgb};
+
+type Pos = (usize, usize);
+
+type StyleFn<'a, T> = dyn Fn(Pos, &T) -> Style + 'a;
+
+/// How a cell is drawn on an ANSI terminal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Style {
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const PLAIN: Style = Style {
+        fg: None,
+        bg: None,
+        bold: false,
+    };
+
+    pub fn fg(self, rgb: Rgb) -> Style {
+        Style {
+            fg: Some(rgb),
+            ..self
+        }
+    }
+
+    pub fn bg(self, rgb: Rgb) -> Style {
+        Style {
+            bg: Some(rgb),
+            ..self
+        }
+    }
+
+    pub fn bold(self) -> Style {
+        Style { bold: true, ..self }
+    }
+
+    /// Escape sequence switching to this style from the default.
+    pub(crate) fn escape(&self) -> String {
+        let mut codes = vec![];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some([r, g, b]) = self.fg {
+            codes.push(format!("38;2;{r};{g};{b}"));
+        }
+        if let Some([r, g, b]) = self.bg {
+            codes.push(format!("48;2;{r};{g};{b}"));
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+pub(crate) const RESET: &str = "\x1b[0m";
+
+/// `text` without the escape sequences written by `Render` and `SideBySide`,
+/// for log sinks that are not terminals.
+pub(crate) fn strip_escapes(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip up to and including the final byte of `ESC [ ... m`
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            plain.push(c);
+        }
+    }
+    Cow::Owned(plain)
+}
+
+/// A grid formatted with a style per cell, see `Grid::render`. Colours are
+/// only written when stdout is a terminal unless `color` says otherwise, and
+/// are always left out of trace files.
+pub struct Render<'a, T> {
+    /// `None` once a viewport is clipped to nothing
+    view: Option<GridView<'a, T>>,
+    style: Box<StyleFn<'a, T>>,
+    color: bool,
+}
+
+impl<T> Grid<T> {
+    /// Renders the grid with each cell in `style` of its position and value.
+    pub fn render<'a>(&'a self, style: impl Fn(Pos, &T) -> Style + 'a) -> Render<'a, T> {
+        self.as_view().render(style)
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Renders the view, with `style` given positions in the whole grid.
+    pub fn render(self, style: impl Fn(Pos, &T) -> Style + 'a) -> Render<'a, T> {
+        Render {
+            view: Some(self),
+            style: Box::new(style),
+            color: io::stdout().is_terminal(),
+        }
+    }
+}
+
+impl<T> Render<'_, T> {
+    /// Width and height of what is rendered.
+    fn size(&self) -> (usize, usize) {
+        self.view
+            .map_or((0, 0), |view| (view.width(), view.height()))
+    }
+
+    /// Only the `width` by `height` window at `pos`, clipped to the grid.
+    /// Renders no cells when nothing of the window is left.
+    pub fn viewport(self, pos: Pos, width: usize, height: usize) -> Self {
+        let (total_width, total_height) = self.size();
+        let width = width.min(total_width.saturating_sub(pos.0));
+        let height = height.min(total_height.saturating_sub(pos.1));
+        let view = match self.view {
+            Some(view) if width > 0 && height > 0 => Some(view.view(pos, width, height)),
+            _ => None,
+        };
+        Render { view, ..self }
+    }
+
+    /// A `width` by `height` viewport as close to centred on `pos` as the
+    /// edges of the grid allow, e.g. to follow a moving player.
+    pub fn around(self, pos: Pos, width: usize, height: usize) -> Self {
+        let start = |at: usize, size: usize, total: usize| {
+            at.saturating_sub(size / 2).min(total.saturating_sub(size))
+        };
+        let (total_width, total_height) = self.size();
+        let x = start(pos.0, width, total_width);
+        let y = start(pos.1, height, total_height);
+        self.viewport((x, y), width, height)
+    }
+
+    /// Forces escape codes on or off.
+    pub fn color(self, color: bool) -> Self {
+        Render { color, ..self }
+    }
+}
+
+impl<T: Display> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        let Some(view) = self.view else {
+            return writeln!(f);
+        };
+        for y in 0..view.height() {
+            let mut current = Style::PLAIN;
+            for x in 0..view.width() {
+                let t = view.at((x, y));
+                if self.color {
+                    let style = (self.style)(view.grid_pos((x, y)), t);
+                    if style != current {
+                        if current != Style::PLAIN {
+                            write!(f, "{RESET}")?;
+                        }
+                        if style != Style::PLAIN {
+                            write!(f, "{}", style.escape())?;
+                        }
+                        current = style;
+                    }
+                }
+                write!(f, "{t}")?;
+            }
+            if current != Style::PLAIN {
+                write!(f, "{RESET}")?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_lines(&["ab.", "..c"], &|c| c)
+    }
+
+    fn style(_: Pos, c: &char) -> Style {
+        match c {
+            'a' | 'b' => Style::PLAIN.fg([255, 0, 0]),
+            'c' => Style::PLAIN.bg([0, 0, 255]).bold(),
+            _ => Style::PLAIN,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = grid();
+        assert_eq!(
+            grid.render(style).color(false).to_string(),
+            grid.to_string()
+        );
+        assert_eq!(
+            grid.render(style).color(true).to_string(),
+            "\n\x1b[38;2;255;0;0mab\x1b[0m.\n..\x1b[1;48;2;0;0;255mc\x1b[0m\n\n"
+        );
+        assert_eq!(
+            strip_escapes(&grid.render(style).color(true).to_string()),
+            grid.to_string()
+        );
+    }
+
+    #[test]
+    fn test_viewport() {
+        let grid = grid();
+        let render = |r: Render<char>| r.color(false).to_string();
+        assert_eq!(
+            render(grid.render(style).viewport((1, 1), 5, 5)),
+            "\n.c\n\n"
+        );
+        assert_eq!(render(grid.render(style).around((0, 0), 2, 1)), "\nab\n\n");
+        assert_eq!(render(grid.render(style).around((2, 1), 2, 1)), "\n.c\n\n");
+
+        // Windows clipped to nothing render no cells
+        assert_eq!(render(grid.render(style).viewport((5, 5), 2, 2)), "\n\n");
+        assert_eq!(render(grid.render(style).around((1, 1), 0, 3)), "\n\n");
+        let empty = Grid::<char>::new(vec![], 0, 0);
+        assert_eq!(render(empty.render(style).around((0, 0), 2, 2)), "\n\n");
+
+        // Styles see grid positions, not viewport positions
+        let marked = grid
+            .render(|pos, _| {
+                if pos == (2, 1) {
+                    Style::PLAIN.bold()
+                } else {
+                    Style::PLAIN
+                }
+            })
+            .viewport((1, 1), 2, 1)
+            .color(true);
+        assert_eq!(marked.to_string(), "\n.\x1b[1mc\x1b[0m\n\n");
+    }
+}
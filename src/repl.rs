@@ -0,0 +1,255 @@
+use lazy_static::lazy_static;
+use log::LevelFilter;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::{get_lines, Config, Day, Input, InputResult, Part, Solver, Target};
+
+lazy_static! {
+    static ref PARAMS: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
+/// Looks up a run parameter set with `--param name=value` or the REPL's
+/// `set` command, letting solvers expose knobs such as a target row.
+pub fn param<T: FromStr>(name: &str) -> Option<T> {
+    PARAMS
+        .lock()
+        .unwrap()
+        .get(name)
+        .and_then(|value| value.parse().ok())
+}
+
+pub fn set_param(name: &str, value: &str) {
+    PARAMS
+        .lock()
+        .unwrap()
+        .insert(name.to_string(), value.to_string());
+}
+
+pub fn unset_param(name: &str) {
+    PARAMS.lock().unwrap().remove(name);
+}
+
+pub type CommandResult = Result<String, String>;
+
+type Action<'a> = Box<dyn Fn(&[&str]) -> CommandResult + 'a>;
+
+struct Command<'a> {
+    usage: &'static str,
+    action: Action<'a>,
+}
+
+/// Day specific commands available in interactive mode, usually closures
+/// over the structures parsed from the loaded input.
+#[derive(Default)]
+pub struct Repl<'a> {
+    commands: BTreeMap<&'static str, Command<'a>>,
+}
+
+impl<'a> Repl<'a> {
+    pub fn new() -> Repl<'a> {
+        Repl {
+            commands: BTreeMap::new(),
+        }
+    }
+
+    pub fn command(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        action: impl Fn(&[&str]) -> CommandResult + 'a,
+    ) {
+        self.commands.insert(
+            name,
+            Command {
+                usage,
+                action: Box::new(action),
+            },
+        );
+    }
+}
+
+const BUILTIN_USAGE: [&str; 8] = [
+    "help: list commands",
+    "load <target> [part]: load a sample number or final from a part's inputs",
+    "run [part]: solve the loaded input",
+    "set <name> <value>: set a parameter read with aoc::param",
+    "unset <name>: remove a parameter",
+    "params: list parameters",
+    "log <level>: change the log level",
+    "quit: exit",
+];
+
+enum Next {
+    Load(Part, Target),
+    Quit,
+}
+
+fn select<'i, 'a, D: InputResult>(
+    inputs: &'i [Input<'a, D>],
+    target: Target,
+) -> Result<(usize, &'i Input<'a, D>), String> {
+    inputs
+        .iter()
+        .enumerate()
+        .find(|(idx, input)| match target {
+            Target::Sample(sample_idx) => input.solution.is_some() && *idx == sample_idx,
+            Target::Samples => input.solution.is_some(),
+            Target::Final | Target::All => input.solution.is_none(),
+        })
+        .ok_or_else(|| format!("No {target} input"))
+}
+
+fn parse_load(args: &[&str]) -> Result<Next, String> {
+    let target = args.first().ok_or("Usage: load <target> [part]")?.parse()?;
+    let part = match args.get(1) {
+        Some(part) => part.parse()?,
+        None => Part::One,
+    };
+    if part == Part::All {
+        return Err("Inputs are loaded from either part 1 or 2".to_string());
+    }
+    Ok(Next::Load(part, target))
+}
+
+/// Loads one input at a time and evaluates commands against it until the
+/// user quits.
+pub(crate) fn interact<'a, D, S>(
+    solver: &S,
+    day: Day,
+    part_one_inputs: &'a [Input<D>],
+    part_two_inputs: &'a [Input<D>],
+    config: &Config,
+) where
+    D: InputResult + 'a,
+    S: Solver<'a, D> + ?Sized,
+{
+    // Start from whatever --part and --target point at
+    let mut part = config.part;
+    let mut target = config.target;
+    if part == Part::All {
+        part = Part::One;
+    }
+
+    loop {
+        let inputs = if part == Part::Two {
+            part_two_inputs
+        } else {
+            part_one_inputs
+        };
+        let (idx, input) = match select(inputs, target) {
+            Ok(selected) => selected,
+            Err(e) if target != Target::Sample(0) => {
+                println!("{e}, falling back to sample #0");
+                target = Target::Sample(0);
+                continue;
+            }
+            Err(e) => {
+                println!("{e}");
+                return;
+            }
+        };
+
+        let data = input.load(day, config);
+        let lines = get_lines(&data);
+        let mut repl = Repl::new();
+        solver.register_commands(&lines, &mut repl);
+        println!(
+            "Loaded {day} part {part} {} ({} lines)",
+            input.label(idx),
+            lines.len()
+        );
+
+        match session(solver, day, &lines, &repl) {
+            Next::Load(new_part, new_target) => {
+                part = new_part;
+                target = new_target;
+            }
+            Next::Quit => return,
+        }
+    }
+}
+
+fn session<'a, D, S>(solver: &S, day: Day, lines: &[&str], repl: &Repl) -> Next
+where
+    D: InputResult + 'a,
+    S: Solver<'a, D> + ?Sized,
+{
+    let stdin = io::stdin();
+    loop {
+        print!("{day}> ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            return Next::Quit;
+        }
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let Some((name, args)) = words.split_first() else {
+            continue;
+        };
+
+        match *name {
+            "help" => {
+                for usage in BUILTIN_USAGE {
+                    println!("  {usage}");
+                }
+                for command in repl.commands.values() {
+                    println!("  {}", command.usage);
+                }
+            }
+            "load" => match parse_load(args) {
+                Ok(action) => return action,
+                Err(e) => println!("{e}"),
+            },
+            "run" => {
+                let part = match args.first().map(|p| p.parse()) {
+                    Some(Ok(part)) => part,
+                    Some(Err(e)) => {
+                        println!("{e}");
+                        continue;
+                    }
+                    None => Part::All,
+                };
+                if part != Part::Two {
+                    let start = Instant::now();
+                    let result = solver.solve_part_one(lines);
+                    println!("Part 1: {result} ({:?})", start.elapsed());
+                }
+                if part != Part::One {
+                    let start = Instant::now();
+                    let result = solver.solve_part_two(lines);
+                    println!("Part 2: {result} ({:?})", start.elapsed());
+                }
+            }
+            "set" => match args {
+                [name, value] => set_param(name, value),
+                _ => println!("Usage: set <name> <value>"),
+            },
+            "unset" => match args {
+                [name] => unset_param(name),
+                _ => println!("Usage: unset <name>"),
+            },
+            "params" => {
+                for (name, value) in PARAMS.lock().unwrap().iter() {
+                    println!("  {name} = {value}");
+                }
+            }
+            "log" => match args.first().map(|level| level.parse::<LevelFilter>()) {
+                Some(Ok(level)) => log::set_max_level(level),
+                _ => println!("Usage: log <off|error|warn|info|debug|trace>"),
+            },
+            "quit" | "exit" => return Next::Quit,
+            _ => match repl.commands.get(name) {
+                Some(command) => match (command.action)(args) {
+                    Ok(output) => println!("{output}"),
+                    Err(e) => println!("{e}\nUsage: {}", command.usage),
+                },
+                None => println!("Unknown command {name}, try help"),
+            },
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::iter::FromIterator;
+
+use crate::{Grid, Point};
+
+/// Unbounded grid keyed by signed coordinates, storing only occupied cells.
+/// The bounding box of the occupied cells is kept up to date as cells are
+/// inserted and removed. Positions can be given as `Point`s or as
+/// `(isize, isize)` tuples.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<P: Into<Point>, T> FromIterator<(P, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn insert(&mut self, pos: impl Into<Point>, value: T) -> Option<T> {
+        let pos = pos.into();
+        self.bounds = Some(match self.bounds {
+            Some((lo, hi)) => (
+                Point::new(min(lo.x, pos.x), min(lo.y, pos.y)),
+                Point::new(max(hi.x, pos.x), max(hi.y, pos.y)),
+            ),
+            None => (pos, pos),
+        });
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: impl Into<Point>) -> Option<T> {
+        let pos = pos.into();
+        let removed = self.cells.remove(&pos);
+        if let (Some(_), Some((lo, hi))) = (&removed, self.bounds) {
+            if pos.x == lo.x || pos.x == hi.x || pos.y == lo.y || pos.y == hi.y {
+                self.recompute_bounds();
+            }
+        }
+        removed
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self.cells.keys().fold(None, |bounds, &pos| {
+            Some(match bounds {
+                Some((lo, hi)) => (
+                    Point::new(min(lo.x, pos.x), min(lo.y, pos.y)),
+                    Point::new(max(hi.x, pos.x), max(hi.y, pos.y)),
+                ),
+                None => (pos, pos),
+            })
+        });
+    }
+
+    pub fn get(&self, pos: impl Into<Point>) -> Option<&T> {
+        self.cells.get(&pos.into())
+    }
+
+    /// Mutable access to an existing cell. Use `insert` to add cells so the
+    /// bounds stay correct.
+    pub fn get_mut(&mut self, pos: impl Into<Point>) -> Option<&mut T> {
+        self.cells.get_mut(&pos.into())
+    }
+
+    pub fn contains(&self, pos: impl Into<Point>) -> bool {
+        self.cells.contains_key(&pos.into())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Inclusive `(min, max)` corners of the occupied cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.map_or(0, |(lo, hi)| (hi.x - lo.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.map_or(0, |(lo, hi)| (hi.y - lo.y + 1) as usize)
+    }
+
+    /// Number of unoccupied cells inside the bounding box.
+    pub fn count_empty(&self) -> usize {
+        self.width() * self.height() - self.len()
+    }
+
+    fn occupied(
+        &self,
+        positions: impl Iterator<Item = Point>,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        positions.filter_map(move |pos| self.cells.get(&pos).map(|value| (pos, value)))
+    }
+
+    /// Occupied cells among the 8 surrounding `pos`.
+    pub fn neighbors(&self, pos: impl Into<Point>) -> impl Iterator<Item = (Point, &T)> {
+        self.occupied(pos.into().neighbors())
+    }
+
+    /// Occupied cells among the 4 orthogonally adjacent to `pos`.
+    pub fn cardinal_neighbors(&self, pos: impl Into<Point>) -> impl Iterator<Item = (Point, &T)> {
+        self.occupied(pos.into().cardinal_neighbors())
+    }
+}
+
+impl<T: Default + Clone> SparseGrid<T> {
+    /// Dense copy of the bounding box, with unoccupied cells set to
+    /// `T::default()`, and the signed position of the dense grid's `(0, 0)`.
+    /// `None` if no cell is occupied.
+    pub fn to_grid(&self) -> Option<(Grid<T>, Point)> {
+        let (lo, _) = self.bounds?;
+        let mut grid = Grid::new_empty(self.width(), self.height());
+        for (&pos, value) in &self.cells {
+            *grid.mut_at(((pos.x - lo.x) as usize, (pos.y - lo.y) as usize)) = value.clone();
+        }
+        Some((grid, lo))
+    }
+
+    /// Sparse copy of the cells of `grid` matching `occupied`, with the
+    /// grid's `(0, 0)` placed at `origin`.
+    pub fn from_grid(
+        grid: &Grid<T>,
+        origin: impl Into<Point>,
+        occupied: impl Fn(&T) -> bool,
+    ) -> Self {
+        let origin = origin.into();
+        grid.positions()
+            .filter(|&pos| occupied(grid.at(pos)))
+            .map(|pos| (origin + Point::from(pos), grid.at(pos).clone()))
+            .collect()
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        if let Some((lo, hi)) = self.bounds {
+            for y in lo.y..=hi.y {
+                for x in lo.x..=hi.x {
+                    match self.cells.get(&Point::new(x, y)) {
+                        Some(value) => write!(f, "{value}")?,
+                        None => write!(f, ".")?,
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_bounds_follow_inserts_and_removes() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point::new(2, 3), '#');
+        grid.insert(Point::new(-1, 5), '#');
+        grid.insert(Point::new(0, -2), '#');
+        assert_eq!(grid.bounds(), Some((Point::new(-1, -2), Point::new(2, 5))));
+        assert_eq!((grid.width(), grid.height()), (4, 8));
+        assert_eq!(grid.count_empty(), 29);
+
+        grid.remove(Point::new(0, -2));
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 3), Point::new(2, 5))));
+        grid.remove(Point::new(2, 3));
+        grid.remove(Point::new(-1, 5));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: SparseGrid<char> = [((0, 0), 'a'), ((1, 1), 'b'), ((0, -1), 'c'), ((5, 5), 'd')]
+            .iter()
+            .map(|&((x, y), c)| (Point::new(x, y), c))
+            .collect();
+        assert_eq!(
+            grid.neighbors(Point::ORIGIN)
+                .map(|(pos, _)| pos)
+                .sorted()
+                .collect_vec(),
+            vec![Point::new(0, -1), Point::new(1, 1)]
+        );
+        assert_eq!(
+            grid.cardinal_neighbors(Point::ORIGIN)
+                .map(|(_, v)| *v)
+                .collect_vec(),
+            vec!['c']
+        );
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let dense = Grid::from_lines(&["#..", ".#.", "..#"], &|c| c);
+        let sparse = SparseGrid::from_grid(&dense, Point::new(-1, -1), |c| *c == '#');
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(
+            sparse.bounds(),
+            Some((Point::new(-1, -1), Point::new(1, 1)))
+        );
+        assert_eq!(sparse.to_string(), "\n#..\n.#.\n..#\n\n");
+
+        let (grid, origin) = sparse.to_grid().unwrap();
+        assert_eq!(origin, Point::new(-1, -1));
+        assert_eq!(
+            grid.state,
+            vec!['#', '\0', '\0', '\0', '#', '\0', '\0', '\0', '#']
+        );
+        assert_eq!(SparseGrid::<char>::new().to_grid(), None);
+    }
+}
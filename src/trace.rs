@@ -0,0 +1,212 @@
+use log::{LevelFilter, Log, Metadata, Record};
+use simple_logger::SimpleLogger;
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::render::strip_escapes;
+use crate::Config;
+
+struct TraceFile {
+    writer: BufWriter<File>,
+    written: u64,
+    max_bytes: Option<u64>,
+    truncated: bool,
+}
+
+impl TraceFile {
+    fn write(&mut self, line: &str) {
+        if self.truncated {
+            return;
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            if self.written + line.len() as u64 > max_bytes {
+                let _ = writeln!(self.writer, "... truncated at {max_bytes} bytes");
+                self.truncated = true;
+                return;
+            }
+        }
+        let _ = self.writer.write_all(line.as_bytes());
+        self.written += line.len() as u64;
+    }
+}
+
+thread_local! {
+    static SINK: RefCell<Option<TraceFile>> = const { RefCell::new(None) };
+}
+
+/// Sends records to the terminal logger, and additionally to the trace file
+/// of the run executing on the current thread, if any.
+struct TraceLogger {
+    terminal: SimpleLogger,
+    level: LevelFilter,
+    modules: Vec<String>,
+}
+
+impl TraceLogger {
+    fn traces(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+            && (self.modules.is_empty()
+                || self
+                    .modules
+                    .iter()
+                    .any(|module| metadata.target().starts_with(module.as_str())))
+            && SINK
+                .try_with(|sink| sink.borrow().is_some())
+                .unwrap_or(false)
+    }
+}
+
+impl Log for TraceLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.terminal.enabled(metadata) || self.traces(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.traces(record.metadata()) {
+            // No timestamps, thread names or colours so traces of different
+            // versions diff cleanly
+            let message = record.args().to_string();
+            let line = format!(
+                "{:<5} [{}] {}\n",
+                record.level(),
+                record.target(),
+                strip_escapes(&message)
+            );
+            SINK.with(|sink| {
+                if let Some(file) = sink.borrow_mut().as_mut() {
+                    file.write(&line);
+                }
+            });
+        }
+        self.terminal.log(record);
+    }
+
+    fn flush(&self) {
+        self.terminal.flush();
+    }
+}
+
+/// Installs `terminal` as the global logger, wrapped so runs can be traced to
+/// files when `trace_dir` is configured.
+pub(crate) fn init(terminal: SimpleLogger, config: &Config) {
+    if config.trace_dir.is_none() {
+        terminal.init().unwrap();
+        return;
+    }
+
+    let max_level = terminal.max_level().max(config.trace_level);
+    log::set_boxed_logger(Box::new(TraceLogger {
+        terminal,
+        level: config.trace_level,
+        modules: config.trace_modules.clone(),
+    }))
+    .unwrap();
+    log::set_max_level(max_level);
+}
+
+/// Records on this thread are copied to the trace file until the guard is
+/// dropped.
+pub(crate) struct TraceGuard;
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        SINK.with(|sink| {
+            if let Some(mut file) = sink.borrow_mut().take() {
+                let _ = file.writer.flush();
+            }
+        });
+    }
+}
+
+pub(crate) fn start(path: &Path, max_bytes: Option<u64>) -> TraceGuard {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .unwrap_or_else(|e| panic!("Could not create {}: {e}", parent.display()));
+    }
+    let file =
+        File::create(path).unwrap_or_else(|e| panic!("Could not create {}: {e}", path.display()));
+    SINK.with(|sink| {
+        *sink.borrow_mut() = Some(TraceFile {
+            writer: BufWriter::new(file),
+            written: 0,
+            max_bytes,
+            truncated: false,
+        })
+    });
+    TraceGuard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Level;
+
+    /// The trace file written for `records` of target and message.
+    fn trace(
+        name: &str,
+        modules: &[&str],
+        max_bytes: Option<u64>,
+        records: &[(&str, std::fmt::Arguments)],
+    ) -> String {
+        let file = format!("aoc-trace-{name}-{}.log", std::process::id());
+        let path = std::env::temp_dir().join(file);
+        let logger = TraceLogger {
+            terminal: SimpleLogger::new().with_level(LevelFilter::Off),
+            level: LevelFilter::Debug,
+            modules: modules.iter().map(|module| module.to_string()).collect(),
+        };
+        let guard = start(&path, max_bytes);
+        for (target, args) in records {
+            logger.log(
+                &Record::builder()
+                    .args(*args)
+                    .level(Level::Debug)
+                    .target(target)
+                    .build(),
+            );
+        }
+        drop(guard);
+        let trace = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        trace
+    }
+
+    #[test]
+    fn test_traces_have_no_colours() {
+        let records = [("2022_12", format_args!("\x1b[1;38;2;255;0;0m#\x1b[0m."))];
+        assert_eq!(
+            trace("colours", &[], None, &records),
+            "DEBUG [2022_12] #.\n"
+        );
+    }
+
+    #[test]
+    fn test_trace_modules() {
+        let records = [
+            ("2022_12", format_args!("kept")),
+            ("aoc::pathfinding", format_args!("dropped")),
+            ("2022_12::inner", format_args!("nested")),
+        ];
+        assert_eq!(
+            trace("modules", &["2022_12"], None, &records),
+            "DEBUG [2022_12] kept\nDEBUG [2022_12::inner] nested\n"
+        );
+    }
+
+    #[test]
+    fn test_trace_max_bytes() {
+        // Each line is 19 bytes, so the third would pass the limit
+        let records = [
+            ("day", format_args!("line 1")),
+            ("day", format_args!("line 2")),
+            ("day", format_args!("line 3")),
+            ("day", format_args!("line 4")),
+        ];
+        assert_eq!(
+            trace("max-bytes", &[], Some(40), &records),
+            "DEBUG [day] line 1\nDEBUG [day] line 2\n... truncated at 40 bytes\n"
+        );
+    }
+}
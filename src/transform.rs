@@ -0,0 +1,140 @@
+use itertools::Itertools;
+
+use crate::Grid;
+
+type Pos = (usize, usize);
+
+impl<T: Default + Clone> Grid<T> {
+    /// Grid of the given size with each cell set to `f` of its position.
+    pub fn from_fn(width: usize, height: usize, f: impl Fn(Pos) -> T) -> Grid<T> {
+        let state = (0..height)
+            .cartesian_product(0..width)
+            .map(|(y, x)| f((x, y)))
+            .collect_vec();
+        Grid::new(state, width, height)
+    }
+
+    /// Rotated a quarter turn clockwise.
+    pub fn rotate_90(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self.at((y, self.height - 1 - x)).clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self.at((self.width - 1 - x, self.height - 1 - y)).clone()
+        })
+    }
+
+    /// Rotated a quarter turn counterclockwise.
+    pub fn rotate_270(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self.at((self.width - 1 - y, x)).clone()
+        })
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self.at((self.width - 1 - x, y)).clone()
+        })
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self.at((x, self.height - 1 - y)).clone()
+        })
+    }
+
+    /// Mirrored along the main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self.at((y, x)).clone())
+    }
+
+    /// The `width` by `height` window with its top left corner at `pos`,
+    /// clipped to the grid. Windows outside the grid give an empty grid.
+    pub fn crop(&self, pos: Pos, width: usize, height: usize) -> Grid<T> {
+        let width = width.min(self.width.saturating_sub(pos.0));
+        let height = height.min(self.height.saturating_sub(pos.1));
+        Grid::from_fn(width, height, |(x, y)| {
+            self.at((pos.0 + x, pos.1 + y)).clone()
+        })
+    }
+
+    /// Surrounded by `margin` cells of `fill` on every side.
+    pub fn pad(&self, margin: usize, fill: T) -> Grid<T> {
+        Grid::from_fn(
+            self.width + 2 * margin,
+            self.height + 2 * margin,
+            |(x, y)| match (x.checked_sub(margin), y.checked_sub(margin)) {
+                (Some(x), Some(y)) => self.get((x, y)).unwrap_or(&fill).clone(),
+                _ => fill.clone(),
+            },
+        )
+    }
+
+    /// `across` copies side by side, repeated `down` times.
+    pub fn tile(&self, across: usize, down: usize) -> Grid<T> {
+        Grid::from_fn(self.width * across, self.height * down, |(x, y)| {
+            self.at((x % self.width, y % self.height)).clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_lines(&["abc", "def"], &|c| c)
+    }
+
+    #[test]
+    fn test_rotations() {
+        let grid = grid();
+        assert_eq!(grid.rotate_90().to_string(), "\nda\neb\nfc\n\n");
+        assert_eq!(grid.rotate_270().to_string(), "\ncf\nbe\nad\n\n");
+        assert_eq!(grid.rotate_180().to_string(), "\nfed\ncba\n\n");
+        assert_eq!(grid.rotate_90().rotate_90(), grid.rotate_180());
+        assert_eq!(grid.rotate_90().rotate_270(), grid);
+        assert_eq!(grid.rotate_90().rotate_90().rotate_90().rotate_90(), grid);
+    }
+
+    #[test]
+    fn test_flips() {
+        let grid = grid();
+        assert_eq!(grid.flip_horizontal().to_string(), "\ncba\nfed\n\n");
+        assert_eq!(grid.flip_vertical().to_string(), "\ndef\nabc\n\n");
+        assert_eq!(grid.flip_horizontal().flip_horizontal(), grid);
+        assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(grid.transpose(), grid.rotate_90().flip_horizontal());
+        assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+    }
+
+    #[test]
+    fn test_crop_pad_tile() {
+        let grid = grid();
+        let padded = grid.pad(2, '.');
+        assert_eq!((padded.width, padded.height), (7, 6));
+        assert_eq!(*padded.at((2, 2)), 'a');
+        assert_eq!(padded.crop((2, 2), 3, 2), grid);
+
+        let tiled = grid.tile(3, 2);
+        assert_eq!((tiled.width, tiled.height), (9, 4));
+        assert_eq!(tiled.crop((3, 2), 3, 2), grid);
+        assert_eq!(tiled.crop((7, 3), 5, 5).to_string(), "\nef\n\n");
+
+        let outside = grid.crop((5, 5), 2, 2);
+        assert_eq!((outside.width, outside.height), (0, 0));
+        assert_eq!(outside.to_string(), "\n\n");
+        let below = grid.crop((1, 5), 2, 2);
+        assert_eq!((below.width, below.height), (2, 0));
+        assert_eq!(below.to_string(), "\n\n");
+        let none = grid.tile(0, 2);
+        assert_eq!((none.width, none.height), (0, 4));
+        assert_eq!(none.to_string(), "\n\n");
+    }
+}
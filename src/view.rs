@@ -0,0 +1,429 @@
+use itertools::Itertools;
+use std::fmt;
+use std::fmt::Display;
+
+use crate::{Grid, CARDINAL_OFFSETS, NEIGHBOR_OFFSETS};
+
+type Pos = (usize, usize);
+
+/// A rectangle of a grid, possibly seen transposed or mirrored. A view
+/// position is transposed first and then mirrored within the rectangle to
+/// find the grid position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Window {
+    origin: Pos,
+    width: usize,
+    height: usize,
+    transposed: bool,
+    flip_x: bool,
+    flip_y: bool,
+}
+
+impl Window {
+    fn new(origin: Pos, width: usize, height: usize) -> Window {
+        Window {
+            origin,
+            width,
+            height,
+            transposed: false,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+
+    fn view_width(&self) -> usize {
+        if self.transposed {
+            self.height
+        } else {
+            self.width
+        }
+    }
+
+    fn view_height(&self) -> usize {
+        if self.transposed {
+            self.width
+        } else {
+            self.height
+        }
+    }
+
+    fn contains(&self, (x, y): Pos) -> bool {
+        x < self.view_width() && y < self.view_height()
+    }
+
+    fn grid_pos(&self, (x, y): Pos) -> Pos {
+        let (x, y) = if self.transposed { (y, x) } else { (x, y) };
+        let x = if self.flip_x { self.width - 1 - x } else { x };
+        let y = if self.flip_y { self.height - 1 - y } else { y };
+        (self.origin.0 + x, self.origin.1 + y)
+    }
+
+    fn sub(&self, pos: Pos, width: usize, height: usize) -> Window {
+        assert!(
+            width > 0 && height > 0 && self.contains((pos.0 + width - 1, pos.1 + height - 1)),
+            "{}x{} window at {:?} does not fit in {}x{} view",
+            width,
+            height,
+            pos,
+            self.view_width(),
+            self.view_height()
+        );
+        let a = self.grid_pos(pos);
+        let b = self.grid_pos((pos.0 + width - 1, pos.1 + height - 1));
+        let (width, height) = if self.transposed {
+            (height, width)
+        } else {
+            (width, height)
+        };
+        Window {
+            origin: (a.0.min(b.0), a.1.min(b.1)),
+            width,
+            height,
+            ..*self
+        }
+    }
+
+    fn transpose(self) -> Window {
+        Window {
+            transposed: !self.transposed,
+            ..self
+        }
+    }
+
+    fn flip_horizontal(self) -> Window {
+        if self.transposed {
+            Window {
+                flip_y: !self.flip_y,
+                ..self
+            }
+        } else {
+            Window {
+                flip_x: !self.flip_x,
+                ..self
+            }
+        }
+    }
+
+    fn flip_vertical(self) -> Window {
+        self.transpose().flip_horizontal().transpose()
+    }
+
+    fn rotate_90(self) -> Window {
+        self.transpose().flip_horizontal()
+    }
+}
+
+/// Read-only window onto part of a `Grid`, without copying cells.
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    window: Window,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+/// Mutable window onto part of a `Grid`.
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    window: Window,
+}
+
+impl<T> Grid<T> {
+    /// The whole grid as a view.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            window: Window::new((0, 0), self.width, self.height),
+        }
+    }
+
+    /// The `width` by `height` rectangle with its top left corner at `pos`.
+    /// Panics if it does not fit in the grid.
+    pub fn view(&self, pos: Pos, width: usize, height: usize) -> GridView<'_, T> {
+        self.as_view().view(pos, width, height)
+    }
+
+    pub fn view_mut(&mut self, pos: Pos, width: usize, height: usize) -> GridViewMut<'_, T> {
+        let window = self.as_view().window.sub(pos, width, height);
+        GridViewMut { grid: self, window }
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    fn with(self, window: Window) -> GridView<'a, T> {
+        GridView { window, ..self }
+    }
+
+    pub fn width(&self) -> usize {
+        self.window.view_width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.window.view_height()
+    }
+
+    /// Position in the underlying grid of `pos` in this view.
+    pub fn grid_pos(&self, pos: Pos) -> Pos {
+        self.window.grid_pos(pos)
+    }
+
+    pub fn at(&self, pos: Pos) -> &'a T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside {}x{} view",
+                pos,
+                self.width(),
+                self.height()
+            )
+        })
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        if !self.window.contains(pos) {
+            return None;
+        }
+        let (x, y) = self.window.grid_pos(pos);
+        Some(&self.grid.state[y * self.grid.width + x])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        (0..self.height())
+            .cartesian_product(0..self.width())
+            .map(|(y, x)| (x, y))
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &'a T)> {
+        let view = *self;
+        self.positions().map(move |pos| (pos, view.at(pos)))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.width()).map(move |x| view.at((x, y)))
+    }
+
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..self.height()).map(move |y| view.at((x, y)))
+    }
+
+    fn neighbors_at_offsets(
+        &self,
+        pos: Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (Pos, &'a T)> {
+        let view = *self;
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = (pos.0.checked_add_signed(dx)?, pos.1.checked_add_signed(dy)?);
+            view.get(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    pub fn enumerate_cardinal_neighbors(&self, pos: Pos) -> impl Iterator<Item = (Pos, &'a T)> {
+        self.neighbors_at_offsets(pos, &CARDINAL_OFFSETS)
+    }
+
+    pub fn enumerate_neighbors(&self, pos: Pos) -> impl Iterator<Item = (Pos, &'a T)> {
+        self.neighbors_at_offsets(pos, &NEIGHBOR_OFFSETS)
+    }
+
+    /// A rectangle of this view, in this view's coordinates and orientation.
+    pub fn view(&self, pos: Pos, width: usize, height: usize) -> GridView<'a, T> {
+        self.with(self.window.sub(pos, width, height))
+    }
+
+    /// Seen rotated a quarter turn clockwise.
+    pub fn rotate_90(&self) -> GridView<'a, T> {
+        self.with(self.window.rotate_90())
+    }
+
+    pub fn rotate_180(&self) -> GridView<'a, T> {
+        self.with(self.window.rotate_90().rotate_90())
+    }
+
+    /// Seen rotated a quarter turn counterclockwise.
+    pub fn rotate_270(&self) -> GridView<'a, T> {
+        self.with(self.window.rotate_90().rotate_90().rotate_90())
+    }
+
+    pub fn flip_horizontal(&self) -> GridView<'a, T> {
+        self.with(self.window.flip_horizontal())
+    }
+
+    pub fn flip_vertical(&self) -> GridView<'a, T> {
+        self.with(self.window.flip_vertical())
+    }
+
+    pub fn transpose(&self) -> GridView<'a, T> {
+        self.with(self.window.transpose())
+    }
+}
+
+impl<T: Default + Clone> GridView<'_, T> {
+    /// Copies the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_fn(self.width(), self.height(), |pos| self.at(pos).clone())
+    }
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f)?;
+        for y in 0..self.height() {
+            for t in self.row(y) {
+                write!(f, "{t}")?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl<T> GridViewMut<'_, T> {
+    /// Read-only access to the same window.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            window: self.window,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.window.view_width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.window.view_height()
+    }
+
+    pub fn at(&self, pos: Pos) -> &T {
+        self.as_view().at(pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.as_view().get(pos)
+    }
+
+    pub fn mut_at(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width(), self.height());
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside {}x{} view", pos, width, height))
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.window.contains(pos) {
+            return None;
+        }
+        let (x, y) = self.window.grid_pos(pos);
+        let width = self.grid.width;
+        Some(&mut self.grid.state[y * width + x])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        self.as_view().positions()
+    }
+
+    /// Sets every cell of the view to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for pos in self.positions().collect_vec() {
+            *self.mut_at(pos) = value.clone();
+        }
+    }
+
+    pub fn rotate_90(self) -> Self {
+        GridViewMut {
+            window: self.window.rotate_90(),
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        GridViewMut {
+            window: self.window.flip_horizontal(),
+            ..self
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        GridViewMut {
+            window: self.window.flip_vertical(),
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        GridViewMut {
+            window: self.window.transpose(),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_lines(&["abcd", "efgh", "ijkl"], &|c| c)
+    }
+
+    #[test]
+    fn test_views_match_copies() {
+        let grid = grid();
+        let view = grid.view((1, 0), 3, 2);
+        let copy = grid.crop((1, 0), 3, 2);
+        assert_eq!(view.to_grid(), copy);
+        assert_eq!(view.rotate_90().to_grid(), copy.rotate_90());
+        assert_eq!(view.rotate_180().to_grid(), copy.rotate_180());
+        assert_eq!(view.rotate_270().to_grid(), copy.rotate_270());
+        assert_eq!(view.flip_horizontal().to_grid(), copy.flip_horizontal());
+        assert_eq!(view.flip_vertical().to_grid(), copy.flip_vertical());
+        assert_eq!(view.transpose().to_grid(), copy.transpose());
+        assert_eq!(
+            view.rotate_90().flip_vertical().to_grid(),
+            copy.rotate_90().flip_vertical()
+        );
+        assert_eq!(
+            view.rotate_90()
+                .rotate_90()
+                .rotate_90()
+                .rotate_90()
+                .to_grid(),
+            copy
+        );
+    }
+
+    #[test]
+    fn test_nested_views() {
+        let grid = grid();
+        let rotated = grid.as_view().rotate_90();
+        assert_eq!(rotated.to_string(), "\niea\njfb\nkgc\nlhd\n\n");
+        let inner = rotated.view((1, 1), 2, 2);
+        assert_eq!(inner.to_string(), "\nfb\ngc\n\n");
+        assert_eq!(inner.grid_pos((0, 0)), (1, 1));
+        assert_eq!(inner.row(1).collect::<String>(), "gc");
+        assert_eq!(
+            inner.enumerate_cardinal_neighbors((0, 0)).collect_vec(),
+            vec![((1, 0), &'b'), ((0, 1), &'g')]
+        );
+    }
+
+    #[test]
+    fn test_view_mut_writes_through() {
+        let mut grid = grid();
+        let mut view = grid.view_mut((2, 1), 2, 2).rotate_90();
+        *view.mut_at((0, 0)) = 'X';
+        assert_eq!(*grid.at((2, 2)), 'X');
+
+        grid.view_mut((0, 0), 2, 1).fill('.');
+        assert_eq!(grid.to_string(), "\n..cd\nefgh\nijXl\n\n");
+    }
+}
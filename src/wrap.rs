@@ -0,0 +1,84 @@
+use crate::{Grid, Point};
+
+type Pos = (usize, usize);
+
+/// How a step that leaves the grid is mapped back onto it.
+pub enum Wrap<'a, T> {
+    /// Steps off the grid are not allowed.
+    Bounded,
+    /// Leaving one edge re-enters at the opposite edge.
+    Torus,
+    /// Like `Torus`, but cells matching the predicate are stepped over as if
+    /// they were not part of the grid, e.g. the blank margins of day 22's map.
+    Skip(Box<dyn Fn(&T) -> bool + 'a>),
+    /// Maps the off-grid target of a step to a cell, or `None` if the step is
+    /// not allowed.
+    Custom(Box<dyn Fn(Point) -> Option<Pos> + 'a>),
+}
+
+impl<'a, T> Wrap<'a, T> {
+    pub fn skip(skipped: impl Fn(&T) -> bool + 'a) -> Wrap<'a, T> {
+        Wrap::Skip(Box::new(skipped))
+    }
+
+    pub fn custom(map: impl Fn(Point) -> Option<Pos> + 'a) -> Wrap<'a, T> {
+        Wrap::Custom(Box::new(map))
+    }
+}
+
+impl<T: Default + Clone> Grid<T> {
+    fn torus_step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Pos {
+        (
+            (x as isize + dx).rem_euclid(self.width as isize) as usize,
+            (y as isize + dy).rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    /// The cell reached by moving `offset` from `pos`, following `wrap` when
+    /// the move leaves the grid.
+    pub fn step(&self, pos: Pos, offset: (isize, isize), wrap: &Wrap<T>) -> Option<Pos> {
+        if let Some(next) = self.offset_pos(pos, offset) {
+            if !matches!(wrap, Wrap::Skip(skipped) if skipped(self.at(next))) {
+                return Some(next);
+            }
+        }
+        match wrap {
+            Wrap::Bounded => None,
+            Wrap::Torus => Some(self.torus_step(pos, offset)),
+            Wrap::Skip(skipped) => {
+                let mut next = self.torus_step(pos, offset);
+                while skipped(self.at(next)) {
+                    if next == pos {
+                        return None;
+                    }
+                    next = self.torus_step(next, offset);
+                }
+                Some(next)
+            }
+            Wrap::Custom(map) => map(Point::from(pos) + Point::from(offset)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wraps() {
+        let grid = Grid::from_lines(&["  .#", " ...", "..  "], &|c| c);
+        assert_eq!(grid.step((1, 1), (-1, 0), &Wrap::Bounded), Some((0, 1)));
+        assert_eq!(grid.step((0, 1), (-1, 0), &Wrap::Bounded), None);
+        assert_eq!(grid.step((0, 1), (-1, 0), &Wrap::Torus), Some((3, 1)));
+        assert_eq!(grid.step((3, 0), (0, -1), &Wrap::Torus), Some((3, 2)));
+
+        let skip = Wrap::skip(|c: &char| *c == ' ');
+        assert_eq!(grid.step((2, 0), (-1, 0), &skip), Some((3, 0)));
+        assert_eq!(grid.step((1, 1), (-1, 0), &skip), Some((3, 1)));
+        assert_eq!(grid.step((3, 1), (0, 1), &skip), Some((3, 0)));
+        assert_eq!(grid.step((2, 1), (1, 0), &skip), Some((3, 1)));
+
+        let clamp = Wrap::custom(|p| Some((p.x.clamp(0, 3) as usize, p.y.clamp(0, 2) as usize)));
+        assert_eq!(grid.step((0, 2), (-1, 1), &clamp), Some((0, 2)));
+    }
+}
@@ -13,17 +13,16 @@ impl Solver<'_, usize> for Solution {
 }
 
 fn main() {
-    let sample = include_str!("../../samples/aaaaa.txt");
-    let input = include_str!("../../inputs/aaaaa.txt");
+    let file = "aaaaa.txt";
     let part_one_problems = [
-        aoc::Input::new_sample(sample, Default::default()), // TODO: Fill in expected sample result
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, Default::default()), // TODO: Fill in expected sample result
+        aoc::Input::final_file(file),
     ];
 
     let part_two_problems = [
-        aoc::Input::new_sample(sample, Default::default()), // TODO: Fill in expected sample result
-        aoc::Input::new_final(input),
+        aoc::Input::sample_file(file, Default::default()), // TODO: Fill in expected sample result
+        aoc::Input::final_file(file),
     ];
 
-    Solution {}.run(&part_one_problems, &part_two_problems);
+    Solution {}.run(aoc::Day::new(yyyy, aaaaa), &part_one_problems, &part_two_problems);
 }